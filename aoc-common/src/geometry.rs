@@ -0,0 +1,288 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// 网格上的坐标，`x` 向右增长，`y` 向下增长
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// 两个点之间的位移
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// 坐标溢出时返回 `None`
+    pub fn checked_add(self, v: impl Into<Vector>) -> Option<Point> {
+        let v = v.into();
+        Some(Point::new(
+            self.x.checked_add(v.dx)?,
+            self.y.checked_add(v.dy)?,
+        ))
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (other - self).chebyshev()
+    }
+}
+
+impl Vector {
+    pub const fn new(dx: i64, dy: i64) -> Self {
+        Vector { dx, dy }
+    }
+
+    pub fn manhattan(self) -> i64 {
+        self.dx.abs() + self.dy.abs()
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        self.dx.abs().max(self.dy.abs())
+    }
+
+    /// 顺时针旋转 90 度（y 轴向下）
+    pub fn rotate_right(self) -> Vector {
+        Vector::new(-self.dy, self.dx)
+    }
+
+    /// 逆时针旋转 90 度（y 轴向下）
+    pub fn rotate_left(self) -> Vector {
+        Vector::new(self.dy, -self.dx)
+    }
+}
+
+/// 上下左右四个方向，`turn_right` 是顺时针
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+}
+
+/// 包括对角线在内的八个方向，按顺时针排列，每次转 45 度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Direction8 {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Direction8 {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::N => Vector::new(0, -1),
+            Direction8::NE => Vector::new(1, -1),
+            Direction8::E => Vector::new(1, 0),
+            Direction8::SE => Vector::new(1, 1),
+            Direction8::S => Vector::new(0, 1),
+            Direction8::SW => Vector::new(-1, 1),
+            Direction8::W => Vector::new(-1, 0),
+            Direction8::NW => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(d: Direction) -> Self {
+        d.vector()
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(d: Direction8) -> Self {
+        d.vector()
+    }
+}
+
+impl<V: Into<Vector>> Add<V> for Point {
+    type Output = Point;
+    fn add(self, v: V) -> Point {
+        let v = v.into();
+        Point::new(self.x + v.dx, self.y + v.dy)
+    }
+}
+
+impl<V: Into<Vector>> AddAssign<V> for Point {
+    fn add_assign(&mut self, v: V) {
+        *self = *self + v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+    fn neg(self) -> Vector {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+    fn mul(self, k: i64) -> Vector {
+        Vector::new(self.dx * k, self.dy * k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.reverse().reverse(), d);
+            assert_eq!(d.turn_right().vector(), d.vector().rotate_right());
+            assert_eq!(d.turn_left().vector(), d.vector().rotate_left());
+            assert_eq!(d.reverse().vector(), -d.vector());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn test_direction8_turns() {
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.reverse().vector(), -d.vector());
+            assert_eq!(d.vector().chebyshev(), 1);
+        }
+        assert_eq!(Direction8::N.turn_right(), Direction8::NE);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::W);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a + Direction::Down, Point::new(1, 3));
+        assert_eq!(a + Vector::new(2, 1) * 3, Point::new(7, 5));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(
+            Point::new(0, 0).checked_add(Direction::Left),
+            Some(Point::new(-1, 0))
+        );
+        assert_eq!(Point::new(i64::MAX, 0).checked_add(Direction::Right), None);
+    }
+}
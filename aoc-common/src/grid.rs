@@ -0,0 +1,403 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use crate::geometry::{Direction, Direction8, Point, Vector};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GridError {
+    #[error("grid is empty")]
+    Empty,
+
+    #[error("line {line} has width {found}, expected {expected}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("{found} cells do not fill a {width}x{height} grid")]
+    Size {
+        width: usize,
+        height: usize,
+        found: usize,
+    },
+
+    #[error("invalid cell {ch:?} at {point:?}")]
+    InvalidCell { ch: char, point: Point },
+}
+
+/// 按行连续存储的二维网格，越界访问通过 `get` 返回 `None`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// 宽和高都不能为 0
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if width == 0 || height == 0 {
+            return Err(GridError::Empty);
+        }
+        // 宽乘高溢出时也不可能和格子数相等
+        if width.checked_mul(height) != Some(cells.len()) {
+            return Err(GridError::Size {
+                width,
+                height,
+                found: cells.len(),
+            });
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// 宽和高都不能为 0
+    pub fn filled(width: usize, height: usize, value: T) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        let len = width.checked_mul(height).ok_or(GridError::Size {
+            width,
+            height,
+            found: 0,
+        })?;
+        Grid::new(width, height, vec![value; len])
+    }
+
+    /// 和自己一样大、每格都是 `value` 的网格，常用来记录每个格子的状态
+    pub fn filled_like<U: Clone>(&self, value: U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: vec![value; self.cells.len()],
+        }
+    }
+
+    /// 每个字符经过 `f` 转换成格子，文本的每一行必须一样宽
+    pub fn parse_with(text: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in text.lines().enumerate() {
+            let mut found = 0;
+            for (x, ch) in line.chars().enumerate() {
+                let cell = f(ch).ok_or(GridError::InvalidCell {
+                    ch,
+                    point: Point::new(x as i64, y as i64),
+                })?;
+                cells.push(cell);
+                found += 1;
+            }
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// 按行优先顺序遍历所有坐标
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    /// 沿 `v` 走一步，走出网格（或坐标溢出）时返回 `None`
+    pub fn step(&self, p: Point, v: impl Into<Vector>) -> Option<Point> {
+        p.checked_add(v).filter(|&next| self.contains(next))
+    }
+
+    /// `p` 周围在网格内的邻居，`directions` 一般是 `Direction::ALL` 或 `Direction8::ALL`
+    pub fn neighbours<D: Into<Vector>>(
+        &self,
+        p: Point,
+        directions: impl IntoIterator<Item = D>,
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let steps: Vec<Vector> = directions.into_iter().map(Into::into).collect();
+        steps.into_iter().filter_map(move |v| {
+            let next = self.step(p, v)?;
+            Some((next, &self[next]))
+        })
+    }
+
+    /// 上下左右四个邻居
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(p, Direction::ALL)
+    }
+
+    /// 包括对角线在内的八个邻居
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbours(p, Direction8::ALL)
+    }
+
+    /// 从 `p`（包含）开始沿 `v` 一直走到网格边界
+    pub fn ray(&self, p: Point, v: impl Into<Vector>) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let v = v.into();
+        std::iter::successors(self.contains(p).then_some(p), move |&q| self.step(q, v))
+            .map(move |q| (q, &self[q]))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} out of width {}", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// 从 `p` 开始向右下方的对角线
+    pub fn diagonal(&self, p: Point) -> impl Iterator<Item = &T> + '_ {
+        self.ray(p, Direction8::SE).map(|(_, cell)| cell)
+    }
+
+    /// 从 `p` 开始向左下方的对角线
+    pub fn anti_diagonal(&self, p: Point) -> impl Iterator<Item = &T> + '_ {
+        self.ray(p, Direction8::SW).map(|(_, cell)| cell)
+    }
+
+    pub fn find(&self, pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(pred).map(|i| self.point_of(i))
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        "abc\ndef\nghi\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid[Point::new(0, 2)], 'g');
+        assert_eq!(grid.to_string(), "abc\ndef\nghi\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert_eq!(
+            "abc\nde\n".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("#.x", |c| matches!(c, '#' | '.').then_some(c)),
+            Err(GridError::InvalidCell {
+                ch: 'x',
+                point: Point::new(2, 0)
+            })
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(grid.row(2), &[5, 6]);
+        assert_eq!(
+            Grid::new(2, 2, vec![1, 2, 3]),
+            Err(GridError::Size {
+                width: 2,
+                height: 2,
+                found: 3
+            })
+        );
+        assert_eq!(Grid::<u8>::new(0, 3, vec![]), Err(GridError::Empty));
+        assert_eq!(Grid::<u8>::new(3, 0, vec![]), Err(GridError::Empty));
+
+        let grid = Grid::filled(3, 2, 0u8).unwrap();
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(Grid::filled(0, 2, 0u8), Err(GridError::Empty));
+
+        // 宽乘高溢出
+        let width = usize::MAX / 2 + 1;
+        assert_eq!(
+            Grid::<u8>::new(width, 2, vec![]),
+            Err(GridError::Size {
+                width,
+                height: 2,
+                found: 0
+            })
+        );
+        assert!(matches!(
+            Grid::filled(width, 2, 0u8),
+            Err(GridError::Size { .. })
+        ));
+
+        let like = sample().filled_like(false);
+        assert_eq!((like.width(), like.height()), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of width 3")]
+    fn test_column_out_of_range() {
+        let _ = sample().column(3);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let four: String = grid.neighbours4(Point::new(0, 0)).map(|(_, c)| c).collect();
+        assert_eq!(four, "bd");
+        let eight: String = grid.neighbours8(Point::new(1, 1)).map(|(_, c)| c).collect();
+        assert_eq!(eight, "bcfihgda");
+        let custom: String = grid
+            .neighbours(Point::new(1, 1), [Vector::new(1, 1), Vector::new(-2, 0)])
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(custom, "i");
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = sample();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(grid.diagonal(Point::new(0, 0)).collect::<String>(), "aei");
+        assert_eq!(
+            grid.anti_diagonal(Point::new(2, 0)).collect::<String>(),
+            "ceg"
+        );
+        let ray: String = grid
+            .ray(Point::new(2, 2), Direction::Left)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(ray, "ihg");
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = sample();
+        assert_eq!(
+            grid.step(Point::new(0, 0), Direction::Right),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(grid.step(Point::new(0, 0), Direction::Up), None);
+        assert_eq!(grid.step(Point::new(2, 2), Direction8::SE), None);
+        assert_eq!(grid.ray(Point::new(5, 5), Direction::Up).count(), 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = sample();
+        assert_eq!(grid.position_of(&'h'), Some(Point::new(1, 2)));
+        assert_eq!(grid.find(|c| *c > 'e'), Some(Point::new(2, 1)));
+        assert_eq!(grid.position_of(&'z'), None);
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("read input file {path} error")]
+    ReadFile {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("read input from stdin error")]
+    ReadStdin(#[source] io::Error),
+}
+
+/// 谜题输入的来源：文件、标准输入，或者编译期嵌入的示例文本
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|source| InputError::ReadFile {
+                    path: path.clone(),
+                    source,
+                })
+            }
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(InputError::ReadStdin)?;
+                Ok(content)
+            }
+            InputSource::Embedded(content) => Ok(content.to_string()),
+        }
+    }
+}
+
+/// 命令行里的 `--input`：`-` 表示标准输入，其余都当成文件路径
+impl FromStr for InputSource {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Embedded(_) => write!(f, "<embedded>"),
+        }
+    }
+}
+
+/// 调用方 crate 目录下的 `data.txt`
+#[macro_export]
+macro_rules! data_file {
+    () => {
+        $crate::InputSource::File(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data.txt"),
+        )
+    };
+}
+
+/// 把调用方 crate 目录下的文件嵌入二进制，例如 `embedded_input!("examples/part1.txt")`
+#[macro_export]
+macro_rules! embedded_input {
+    ($path:literal) => {
+        $crate::InputSource::Embedded(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/",
+            $path
+        )))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_embedded() {
+        let source = InputSource::Embedded("1 2\n3 4\n");
+        assert_eq!(source.read().unwrap(), "1 2\n3 4\n");
+    }
+
+    #[test]
+    fn test_missing_file_error_contains_path() {
+        let source = InputSource::File(PathBuf::from("/no/such/dir/data.txt"));
+        let err = source.read().unwrap_err();
+        assert!(matches!(err, InputError::ReadFile { .. }));
+        assert!(err.to_string().contains("/no/such/dir/data.txt"));
+    }
+
+    #[test]
+    fn test_parse_from_cli_arg() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "inputs/day5.txt".parse::<InputSource>().unwrap(),
+            InputSource::File(PathBuf::from("inputs/day5.txt"))
+        );
+    }
+
+    #[test]
+    fn test_data_file_macro() {
+        let source = data_file!();
+        assert_eq!(
+            source,
+            InputSource::File(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data.txt"))
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod solution;
+
+pub use geometry::{Direction, Direction8, Point, Vector};
+pub use grid::{Grid, GridError};
+pub use input::{InputError, InputSource};
+pub use solution::{is_not_implemented, Answer, NotImplemented, Solution};
@@ -0,0 +1,120 @@
+use std::fmt::{self, Display};
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::InputSource;
+
+/// 某一部分的答案，统一转成字符串方便输出和比较
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(i32, i64, u32, u64, usize, String, &str);
+
+/// 还没有完成的部分返回这个错误，runner 会跳过而不是当成失败
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("not implemented yet")]
+pub struct NotImplemented;
+
+pub fn is_not_implemented(err: &anyhow::Error) -> bool {
+    err.is::<NotImplemented>()
+}
+
+/// 每一天的解法都实现这个 trait：先 `parse` 成 `Input`，两部分共用同一份解析结果
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input: 'static;
+
+    /// 默认输入，一般是 `data_file!()`
+    fn data_file() -> InputSource;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(NotImplemented.into())
+    }
+}
+
+/// 用 `examples/` 目录下的示例输入生成测试，约定每个示例文件对应一个期望答案：
+///
+/// ```ignore
+/// aoc_common::example_tests! {
+///     Day3;
+///     test_part1: part1("examples/part1.txt") => 161,
+///     test_part2: part2("examples/part2.txt") => 48,
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty; $($(#[$meta:meta])* $name:ident: $part:ident($path:literal) => $expected:expr),* $(,)?) => {
+        $(
+            #[test]
+            $(#[$meta])*
+            fn $name() {
+                let raw = $crate::embedded_input!($path).read().unwrap();
+                let input = <$solution as $crate::Solution>::parse(&raw).unwrap();
+                let answer = <$solution as $crate::Solution>::$part(&input).unwrap();
+                assert_eq!(answer.to_string(), ($expected).to_string());
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Dummy";
+        type Input = Vec<i64>;
+
+        fn data_file() -> InputSource {
+            InputSource::Embedded("1\n2\n3\n")
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().map(|l| l.parse()).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn test_parse_then_solve() {
+        let input = Dummy::parse(&Dummy::data_file().read().unwrap()).unwrap();
+        assert_eq!(Dummy::part1(&input).unwrap(), Answer::from(6i64));
+    }
+
+    #[test]
+    fn test_default_part2_is_not_implemented() {
+        let err = Dummy::part2(&vec![]).unwrap_err();
+        assert!(is_not_implemented(&err));
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// toml 里的答案既可以写成整数也可以写成字符串
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl Recorded {
+    fn into_string(self) -> String {
+        match self {
+            Recorded::Number(n) => n.to_string(),
+            Recorded::Text(s) => s,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Recorded>,
+    part2: Option<Recorded>,
+}
+
+/// 工作区根目录下 `answers.toml` 里记录的正确答案
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_root().join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("read answers file {} error", path.display()))?;
+        Self::parse(&content)
+            .with_context(|| format!("parse answers file {} error", path.display()))
+    }
+
+    pub(crate) fn parse(content: &str) -> Result<Self> {
+        let table: BTreeMap<String, DayAnswers> = toml::from_str(content)?;
+        let mut answers = BTreeMap::new();
+        for (key, day_answers) in table {
+            let day: u8 = key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .with_context(|| format!("invalid day table [{}], expected [dayN]", key))?;
+            if let Some(answer) = day_answers.part1 {
+                answers.insert((day, 1), answer.into_string());
+            }
+            if let Some(answer) = day_answers.part2 {
+                answers.insert((day, 2), answer.into_string());
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers_and_strings() {
+        let answers = Answers::parse(
+            r#"
+            [day1]
+            part1 = 11
+            part2 = "31"
+
+            [day12]
+            part1 = "abc"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("11"));
+        assert_eq!(answers.get(1, 2), Some("31"));
+        assert_eq!(answers.get(12, 1), Some("abc"));
+        assert_eq!(answers.get(12, 2), None);
+    }
+
+    #[test]
+    fn test_reject_bad_table_name() {
+        assert!(Answers::parse("[first]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[day1]\npart3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_workspace_answers_file_parses() {
+        Answers::load(&Answers::default_path()).unwrap();
+    }
+}
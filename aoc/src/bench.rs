@@ -0,0 +1,242 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use aoc_common::is_not_implemented;
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// 多次迭代的耗时统计，单位纳秒
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let mid = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[mid - 1] + nanos[mid]) / 2
+        } else {
+            nanos[mid]
+        };
+        Stats {
+            min_ns: nanos[0],
+            median_ns,
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    /// `parse`、`part1` 或 `part2`
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// 写到磁盘上的基准报告，条目按天和阶段排好序，方便在不同提交之间 diff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub entries: Vec<Entry>,
+}
+
+impl Report {
+    pub fn default_path() -> PathBuf {
+        crate::workspace_root().join("bench.json")
+    }
+
+    /// 读取上一次的报告，文件不存在时返回 `None`
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("read bench report {} error", path.display()))?;
+        let report = serde_json::from_str(&content)
+            .with_context(|| format!("parse bench report {} error", path.display()))?;
+        Ok(Some(report))
+    }
+
+    pub fn median(&self, day: u8, stage: &str) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.stage == stage)
+            .map(|e| Duration::from_nanos(e.stats.median_ns))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n")
+            .with_context(|| format!("write bench report {} error", path.display()))
+    }
+}
+
+fn time<T>(mut f: impl FnMut() -> Result<T>) -> Result<(Duration, T)> {
+    let start = Instant::now();
+    let value = black_box(f()?);
+    Ok((start.elapsed(), value))
+}
+
+/// 对每一天计时；某一天解析失败或某一部分出错时报出来并跳过，其他天照常写进报告
+///
+/// 返回的布尔值表示是否没有出错。
+pub fn bench(days: &[Day], iterations: usize) -> (Report, bool) {
+    let mut entries = vec![];
+    let mut all_ok = true;
+    'days: for day in days {
+        // 还没有输入的天跳过，不影响其他天
+        let raw = match (day.data_file)().read() {
+            Ok(raw) => raw,
+            Err(err) => {
+                eprintln!("day {}: skipped: {:#}", day.day, anyhow::Error::new(err));
+                continue;
+            }
+        };
+
+        let mut parse_samples = vec![];
+        let mut part_samples = [vec![], vec![]];
+        // 未实现或出错的部分不再计时，也不写进报告
+        let mut active = [true, true];
+        for _ in 0..iterations {
+            let (elapsed, input) = match time(|| (day.parse)(&raw)) {
+                Ok(timed) => timed,
+                Err(err) => {
+                    eprintln!("day {}: parse error: {:#}", day.day, err);
+                    all_ok = false;
+                    continue 'days;
+                }
+            };
+            parse_samples.push(elapsed);
+            for part in [1, 2] {
+                let i = part as usize - 1;
+                if !active[i] {
+                    continue;
+                }
+                match time(|| day.solve(part, &input)) {
+                    Ok((elapsed, _)) => part_samples[i].push(elapsed),
+                    Err(err) if is_not_implemented(&err) => active[i] = false,
+                    Err(err) => {
+                        eprintln!("day {} part {}: error: {:#}", day.day, part, err);
+                        all_ok = false;
+                        active[i] = false;
+                    }
+                }
+            }
+        }
+
+        entries.push(Entry {
+            day: day.day,
+            stage: "parse".to_string(),
+            stats: Stats::from_samples(&parse_samples),
+        });
+        for (i, samples) in part_samples.iter().enumerate() {
+            if active[i] {
+                entries.push(Entry {
+                    day: day.day,
+                    stage: format!("part{}", i + 1),
+                    stats: Stats::from_samples(samples),
+                });
+            }
+        }
+    }
+    let report = Report {
+        iterations,
+        entries,
+    };
+    (report, all_ok)
+}
+
+pub fn print(report: &Report) {
+    for entry in &report.entries {
+        let stats = &entry.stats;
+        println!(
+            "day {} {:<5}  min {:>12?}  median {:>12?}  max {:>12?}",
+            entry.day,
+            entry.stage,
+            Duration::from_nanos(stats.min_ns),
+            Duration::from_nanos(stats.median_ns),
+            Duration::from_nanos(stats.max_ns),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, InputSource, NotImplemented};
+
+    use super::*;
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let samples = [30, 10, 20].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                min_ns: 10,
+                median_ns: 20,
+                max_ns: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let samples = [40, 10, 20, 30].map(Duration::from_nanos);
+        assert_eq!(Stats::from_samples(&samples).median_ns, 25);
+    }
+
+    #[test]
+    fn test_bench_skips_failures() {
+        let broken = Day {
+            day: 1,
+            title: "Broken",
+            data_file: || InputSource::Embedded(""),
+            parse: |_| anyhow::bail!("bad input"),
+            part1: |_| unreachable!(),
+            part2: |_| unreachable!(),
+        };
+        let partial = Day {
+            day: 2,
+            title: "Partial",
+            data_file: || InputSource::Embedded(""),
+            parse: |_| Ok(Box::new(())),
+            part1: |_| Ok(Answer::from(1)),
+            part2: |_| anyhow::bail!("boom"),
+        };
+        let unfinished = Day {
+            day: 3,
+            title: "Unfinished",
+            data_file: || InputSource::Embedded(""),
+            parse: |_| Ok(Box::new(())),
+            part1: |_| Ok(Answer::from(1)),
+            part2: |_| Err(NotImplemented.into()),
+        };
+
+        let (report, ok) = bench(&[broken, partial], 3);
+        assert!(!ok);
+        let stages: Vec<(u8, &str)> = report
+            .entries
+            .iter()
+            .map(|entry| (entry.day, entry.stage.as_str()))
+            .collect();
+        assert_eq!(stages, vec![(2, "parse"), (2, "part1")]);
+
+        // 未实现不算出错
+        let (report, ok) = bench(&[unfinished], 3);
+        assert!(ok);
+        assert_eq!(report.entries.len(), 2);
+    }
+}
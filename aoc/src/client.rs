@@ -0,0 +1,291 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/hildxd/advent-of-code-2024-rust";
+const YEAR: u16 = 2024;
+
+/// 本地状态目录（session、上次请求时间等），不提交到仓库
+pub fn state_dir(root: &Path) -> PathBuf {
+    root.join(".aoc")
+}
+
+/// session 优先取命令行/环境变量 `AOC_SESSION`，其次是 `.aoc/session` 文件
+pub fn load_session(from_env: Option<&str>, root: &Path) -> Result<String> {
+    if let Some(session) = from_env {
+        return Ok(session.trim().to_string());
+    }
+    let path = state_dir(root).join("session");
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "no session token: set AOC_SESSION or write it to {}",
+            path.display()
+        )
+    })?;
+    Ok(session.trim().to_string())
+}
+
+/// 两次请求之间至少间隔 `min_interval`，上次请求的时间记录在文件里，跨进程也生效
+pub struct RateLimiter {
+    state_file: PathBuf,
+    min_interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(state_file: PathBuf, min_interval: Duration) -> Self {
+        RateLimiter {
+            state_file,
+            min_interval,
+        }
+    }
+
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    pub fn wait(&self) {
+        let Some(last) = fs::read_to_string(&self.state_file)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+        else {
+            return;
+        };
+        let elapsed = Self::now().saturating_sub(Duration::from_millis(last));
+        if elapsed < self.min_interval {
+            thread::sleep(self.min_interval - elapsed);
+        }
+    }
+
+    pub fn record(&self) -> Result<()> {
+        if let Some(dir) = self.state_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.state_file, Self::now().as_millis().to_string())
+            .with_context(|| format!("write {} error", self.state_file.display()))
+    }
+}
+
+/// Advent of Code 网站的客户端，`base_url` 可以换成本地的测试服务
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, limiter: RateLimiter) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            limiter,
+        }
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn send(&self, request: ureq::Request, body: Option<&[(&str, &str)]>) -> Result<String> {
+        let url = request.url().to_string();
+        self.limiter.wait();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match body {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        self.limiter.record()?;
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!("{} returned {}: {}", url, code, body.trim())
+            }
+            Err(err) => Err(err).with_context(|| format!("request {} error", url)),
+        }
+    }
+
+    pub fn get_input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        self.send(self.agent.get(&url), None)
+    }
+
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        self.send(
+            self.agent.post(&url),
+            Some(&[("level", &level), ("answer", answer)]),
+        )
+    }
+}
+
+/// 下载某一天的输入到 `day<N>/data.txt`，已经存在时除非 `force` 否则不覆盖
+///
+/// `client` 只在真的要下载时才调用，不下载时不需要 session。
+pub fn fetch(
+    client: impl FnOnce() -> Result<Client>,
+    root: &Path,
+    day: u8,
+    force: bool,
+) -> Result<()> {
+    let crate_dir = root.join(format!("day{}", day));
+    if !crate_dir.is_dir() {
+        bail!(
+            "{} does not exist, run `aoc new --day {}` first",
+            crate_dir.display(),
+            day
+        );
+    }
+    let path = crate_dir.join("data.txt");
+    if path.exists() && !force {
+        println!(
+            "{} already exists, use --force to download again",
+            path.display()
+        );
+        return Ok(());
+    }
+    let input = client()?.get_input(day)?;
+    fs::write(&path, input).with_context(|| format!("write {} error", path.display()))?;
+    println!("saved {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// 只应答一次的本地 HTTP 服务，返回地址和收到的原始请求
+    pub fn stub_server(status: &str, body: &str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+        (addr, rx)
+    }
+
+    pub fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    pub fn client(base_url: &str, root: &Path) -> Client {
+        let limiter = RateLimiter::new(state_dir(root).join("last-request"), Duration::ZERO);
+        Client::new(base_url, "secret".to_string(), limiter)
+    }
+
+    #[test]
+    fn test_fetch_writes_input_with_session_cookie() {
+        let root = temp_root("fetch");
+        fs::create_dir_all(root.join("day3")).unwrap();
+        let (url, requests) = stub_server("200 OK", "mul(2,4)\n");
+
+        fetch(|| Ok(client(&url, &root)), &root, 3, false).unwrap();
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/3/input "));
+        assert!(request.contains("session=secret"));
+        assert_eq!(
+            fs::read_to_string(root.join("day3/data.txt")).unwrap(),
+            "mul(2,4)\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_does_not_overwrite_without_force() {
+        let root = temp_root("no-overwrite");
+        fs::create_dir_all(root.join("day3")).unwrap();
+        fs::write(root.join("day3/data.txt"), "old").unwrap();
+
+        // 不会下载，所以也不会去读 session
+        fetch(|| unreachable!("client is not needed"), &root, 3, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("day3/data.txt")).unwrap(),
+            "old"
+        );
+
+        let (url, _requests) = stub_server("200 OK", "new");
+        fetch(|| Ok(client(&url, &root)), &root, 3, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("day3/data.txt")).unwrap(),
+            "new"
+        );
+    }
+
+    #[test]
+    fn test_fetch_reports_http_errors() {
+        let root = temp_root("http-error");
+        fs::create_dir_all(root.join("day3")).unwrap();
+        let (url, _requests) = stub_server("400 Bad Request", "Puzzle inputs differ by user.");
+
+        let err = fetch(|| Ok(client(&url, &root)), &root, 3, false).unwrap_err();
+        assert!(err.to_string().contains("400"));
+        assert!(!root.join("day3/data.txt").exists());
+    }
+
+    #[test]
+    fn test_rate_limiter_waits_between_requests() {
+        let root = temp_root("rate-limit");
+        let limiter = RateLimiter::new(root.join("last-request"), Duration::from_millis(200));
+        limiter.wait();
+        limiter.record().unwrap();
+        let start = Instant::now();
+        limiter.wait();
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn test_load_session() {
+        let root = temp_root("session");
+        assert!(load_session(None, &root).is_err());
+        fs::create_dir_all(state_dir(&root)).unwrap();
+        fs::write(state_dir(&root).join("session"), "abc\n").unwrap();
+        assert_eq!(load_session(None, &root).unwrap(), "abc");
+        assert_eq!(load_session(Some("xyz"), &root).unwrap(), "xyz");
+    }
+}
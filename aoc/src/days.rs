@@ -0,0 +1,79 @@
+use std::any::Any;
+
+use anyhow::Result;
+use aoc_common::{Answer, InputSource, Solution};
+
+/// 擦除了具体类型的解析结果
+pub type ParsedInput = Box<dyn Any>;
+
+/// 擦除了具体类型的某一天解法，方便放进同一个列表里调度
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub data_file: fn() -> InputSource,
+    pub parse: fn(&str) -> Result<ParsedInput>,
+    pub part1: fn(&ParsedInput) -> Result<Answer>,
+    pub part2: fn(&ParsedInput) -> Result<Answer>,
+}
+
+fn parse<S: Solution>(input: &str) -> Result<ParsedInput> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("parsed input belongs to another day")
+}
+
+fn part1<S: Solution>(input: &ParsedInput) -> Result<Answer> {
+    S::part1(downcast::<S>(input))
+}
+
+fn part2<S: Solution>(input: &ParsedInput) -> Result<Answer> {
+    S::part2(downcast::<S>(input))
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            data_file: S::data_file,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: u8, input: &ParsedInput) -> Result<Answer> {
+        match part {
+            1 => (self.part1)(input),
+            _ => (self.part2)(input),
+        }
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day3::Day3>(),
+        Day::of::<day4::Day4>(),
+        Day::of::<day5::Day5>(),
+        Day::of::<day6::Day6>(),
+        Day::of::<day7::Day7>(),
+    ]
+}
+
+/// 按 `--day` 选出要运行的天，没有指定就是全部
+pub fn select(day: Option<u8>) -> Result<Vec<Day>> {
+    let days: Vec<Day> = all()
+        .into_iter()
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect();
+    if days.is_empty() {
+        anyhow::bail!("day {} is not solved yet", day.unwrap_or_default());
+    }
+    Ok(days)
+}
@@ -0,0 +1,294 @@
+mod answers;
+mod bench;
+mod client;
+mod days;
+mod readme;
+mod scaffold;
+mod submit;
+mod verify;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use answers::Answers;
+use anyhow::Result;
+use aoc_common::{is_not_implemented, Answer, InputSource};
+use bench::Report;
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 运行某一天（或全部）的解法并输出答案
+    Run(RunArgs),
+    /// 运行所有已实现的部分，并和 answers.toml 里记录的答案比较
+    Verify(VerifyArgs),
+    /// 对每一天的 parse、part1、part2 分别计时，并写出 JSON 报告
+    Bench(BenchArgs),
+    /// 从模板生成新一天的 crate，并注册到工作区和 runner
+    New(NewArgs),
+    /// 根据实现、校验和基准结果重新生成 README 里的进度表
+    Readme(ReadmeArgs),
+    /// 下载某一天的谜题输入到 `day<N>/data.txt`
+    Fetch(FetchArgs),
+    /// 提交某一部分的答案，并记录到 .aoc/submissions.json
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// 第几天，不指定则运行全部
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// 第几部分，不指定则两部分都运行
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// 输入文件路径，`-` 表示从标准输入读取；默认是该天目录下的 data.txt
+    #[arg(short, long, requires = "day")]
+    input: Option<InputSource>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// 第几天，不指定则校验全部
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// 答案文件路径，默认是工作区根目录下的 answers.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// 第几天，不指定则测全部
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// 每个阶段重复运行的次数
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// 报告输出路径，默认是工作区根目录下的 bench.json
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// 第几天
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// 谜题标题，默认是 `Day N`
+    #[arg(short, long)]
+    title: Option<String>,
+}
+
+#[derive(Args)]
+struct ReadmeArgs {
+    /// 答案文件路径，默认是工作区根目录下的 answers.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// 基准报告路径，默认是工作区根目录下的 bench.json
+    #[arg(long)]
+    bench: Option<PathBuf>,
+}
+
+/// 访问 Advent of Code 网站需要的参数
+#[derive(Args)]
+struct ClientArgs {
+    /// 网站地址，测试时可以指向本地的替身服务
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// 登录后 cookie 里的 session，不指定则读取 .aoc/session
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// 两次请求之间的最小间隔（秒）
+    #[arg(long, default_value_t = 5)]
+    min_interval: u64,
+}
+
+impl ClientArgs {
+    fn client(&self) -> Result<client::Client> {
+        let root = workspace_root();
+        let session = client::load_session(self.session.as_deref(), &root)?;
+        let limiter = client::RateLimiter::new(
+            client::state_dir(&root).join("last-request"),
+            Duration::from_secs(self.min_interval),
+        );
+        Ok(client::Client::new(&self.base_url, session, limiter))
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// 第几天
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// 覆盖已经存在的 data.txt
+    #[arg(long)]
+    force: bool,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// 第几天
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// 第几部分
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// 要提交的答案，不指定则用默认输入运行解法得到
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// 工作区根目录，runner 自己在 `aoc/` 下面
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the workspace")
+        .to_path_buf()
+}
+
+/// 用默认输入求出某一部分的答案
+fn solve_default(day: u8, part: u8) -> Result<Answer> {
+    let days = days::select(Some(day))?;
+    let day = &days[0];
+    let input = (day.parse)(&(day.data_file)().read()?)?;
+    day.solve(part, &input)
+}
+
+/// 输出格式固定为 `day <N> part <P>: <answer>`，方便脚本抓取
+fn run(args: &RunArgs) -> Result<bool> {
+    let mut all_ok = true;
+    for day in days::select(args.day)? {
+        let source = args.input.clone().unwrap_or_else(day.data_file);
+        // 某一天读不到或解析不了输入时报出来，继续跑其他天
+        let input = match source.read().map_err(anyhow::Error::from) {
+            Ok(raw) => (day.parse)(&raw),
+            Err(err) => Err(err),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day {}: error: {:#}", day.day, err);
+                all_ok = false;
+                continue;
+            }
+        };
+        for part in args.parts() {
+            match day.solve(part, &input) {
+                Ok(answer) => println!("day {} part {}: {}", day.day, part, answer),
+                Err(err) if is_not_implemented(&err) => {
+                    eprintln!("day {} part {}: {}", day.day, part, err);
+                }
+                Err(err) => {
+                    eprintln!("day {} part {}: error: {:#}", day.day, part, err);
+                    all_ok = false;
+                }
+            }
+        }
+    }
+    Ok(all_ok)
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    let ok = match &cli.command {
+        Command::Run(args) => run(args)?,
+        Command::Verify(args) => {
+            let path = args.answers.clone().unwrap_or_else(Answers::default_path);
+            verify::verify(&days::select(args.day)?, &Answers::load(&path)?)?
+        }
+        Command::Bench(args) => {
+            let (report, ok) = bench::bench(&days::select(args.day)?, args.iterations as usize);
+            bench::print(&report);
+            let path = args.output.clone().unwrap_or_else(Report::default_path);
+            report.save(&path)?;
+            ok
+        }
+        Command::New(args) => {
+            let title = args.title.clone().unwrap_or(format!("Day {}", args.day));
+            scaffold::new_day(&workspace_root(), args.day, &title)?;
+            true
+        }
+        Command::Readme(args) => {
+            let answers = args.answers.clone().unwrap_or_else(Answers::default_path);
+            let bench = args.bench.clone().unwrap_or_else(Report::default_path);
+            readme::update(
+                &workspace_root().join("README.md"),
+                &days::all(),
+                &Answers::load(&answers)?,
+                Report::load(&bench)?.as_ref(),
+            )?;
+            true
+        }
+        Command::Fetch(args) => {
+            client::fetch(
+                || args.client.client(),
+                &workspace_root(),
+                args.day,
+                args.force,
+            )?;
+            true
+        }
+        Command::Submit(args) => {
+            let answer = match &args.answer {
+                Some(answer) => answer.clone(),
+                None => solve_default(args.day, args.part)?.to_string(),
+            };
+            let history = submit::History::default_path(&workspace_root());
+            let verdict = submit::submit(
+                || args.client.client(),
+                &history,
+                args.day,
+                args.part,
+                &answer,
+            )?;
+            println!(
+                "day {} part {}: {} -> {:?}",
+                args.day, args.part, answer, verdict
+            );
+            verdict == submit::Verdict::Correct
+        }
+    };
+    Ok(if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,120 @@
+use std::{fs, path::Path, time::Duration};
+
+use anyhow::{Context, Result};
+
+use crate::{answers::Answers, bench::Report, days::Day, verify};
+
+const START_MARKER: &str = "<!-- progress:start -->";
+const END_MARKER: &str = "<!-- progress:end -->";
+
+fn status(outcome: &verify::Outcome) -> &'static str {
+    match outcome {
+        verify::Outcome::Pass => "✅",
+        verify::Outcome::Missing { .. } => "🟡 未校验",
+        verify::Outcome::Fail { .. } | verify::Outcome::Error(_) => "❌ 答案错误",
+        verify::Outcome::NoInput(_) => "⬜ 缺少输入",
+        verify::Outcome::NotImplemented => "⬜ 未完成",
+    }
+}
+
+fn timing(report: Option<&Report>, day: u8) -> String {
+    let Some(report) = report else {
+        return "-".to_string();
+    };
+    let stages: Vec<String> = ["parse", "part1", "part2"]
+        .iter()
+        .map(|stage| match report.median(day, stage) {
+            Some(d) => format_duration(d),
+            None => "-".to_string(),
+        })
+        .collect();
+    stages.join(" / ")
+}
+
+fn format_duration(d: Duration) -> String {
+    if d >= Duration::from_secs(1) {
+        format!("{:.2}s", d.as_secs_f64())
+    } else if d >= Duration::from_millis(1) {
+        format!("{:.2}ms", d.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.0}µs", d.as_secs_f64() * 1e6)
+    }
+}
+
+/// 生成进度表格，耗时取自上一次 `aoc bench` 的中位数
+pub fn render_table(days: &[Day], answers: &Answers, report: Option<&Report>) -> String {
+    let mut table = String::new();
+    table.push_str("| Day | 谜题 | Part 1 | Part 2 | 耗时 (parse / part1 / part2) |\n");
+    table.push_str("| --- | --- | --- | --- | --- |\n");
+    for day in days {
+        let [part1, part2] = verify::check(day, answers);
+        table.push_str(&format!(
+            "| {} | [{}](https://adventofcode.com/2024/day/{}) | {} | {} | {} |\n",
+            day.day,
+            day.title,
+            day.day,
+            status(&part1),
+            status(&part2),
+            timing(report, day.day),
+        ));
+    }
+    table
+}
+
+/// 替换 README 里两个标记之间的内容，标记本身保留
+fn replace_section(readme: &str, table: &str) -> Result<String> {
+    let start = readme
+        .find(START_MARKER)
+        .with_context(|| format!("README is missing {}", START_MARKER))?
+        + START_MARKER.len();
+    let end = readme
+        .find(END_MARKER)
+        .with_context(|| format!("README is missing {}", END_MARKER))?;
+    if end < start {
+        anyhow::bail!("{} appears before {}", END_MARKER, START_MARKER);
+    }
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+pub fn update(path: &Path, days: &[Day], answers: &Answers, report: Option<&Report>) -> Result<()> {
+    let readme =
+        fs::read_to_string(path).with_context(|| format!("read {} error", path.display()))?;
+    let table = render_table(days, answers, report);
+    fs::write(path, replace_section(&readme, &table)?)
+        .with_context(|| format!("write {} error", path.display()))?;
+    println!("updated {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# title\n\n<!-- progress:start -->\nold\n<!-- progress:end -->\n\nfooter\n";
+        let result = replace_section(readme, "| new |\n").unwrap();
+        assert_eq!(
+            result,
+            "# title\n\n<!-- progress:start -->\n\n| new |\n\n<!-- progress:end -->\n\nfooter\n"
+        );
+        assert_eq!(replace_section(&result, "| new |\n").unwrap(), result);
+    }
+
+    #[test]
+    fn test_replace_section_requires_markers() {
+        assert!(replace_section("# title\n", "").is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(113)), "113µs");
+        assert_eq!(format_duration(Duration::from_micros(46_331)), "46.33ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use toml_edit::{value, DocumentMut, InlineTable, Value};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tpl");
+
+// 标题替换成转义过的 Rust 字符串字面量，里面有引号或反斜杠也能编译
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", &format!("{:?}", title))
+}
+
+/// 把新的 crate 加到工作区根目录 `Cargo.toml` 的 `members` 里
+fn register_member(manifest: &str, day: u8) -> Result<String> {
+    let mut doc: DocumentMut = manifest.parse()?;
+    let members = doc["workspace"]["members"]
+        .as_array_mut()
+        .context("workspace.members is not an array")?;
+    let name = format!("day{}", day);
+    if members.iter().any(|m| m.as_str() == Some(&name)) {
+        bail!("{} is already a workspace member", name);
+    }
+    members.push(name);
+    Ok(doc.to_string())
+}
+
+/// 让 runner 依赖新的 crate
+fn register_dependency(manifest: &str, day: u8) -> Result<String> {
+    let mut doc: DocumentMut = manifest.parse()?;
+    let mut dep = InlineTable::new();
+    dep.insert("path", Value::from(format!("../day{}", day)));
+    doc["dependencies"][&format!("day{}", day)] = value(dep);
+    Ok(doc.to_string())
+}
+
+/// 在 `days::all()` 的列表末尾追加新的一天
+fn register_day(days_rs: &str, day: u8) -> Result<String> {
+    let entry = format!("        Day::of::<day{day}::Day{day}>(),\n");
+    let last = days_rs
+        .match_indices("        Day::of::<day")
+        .last()
+        .map(|(i, _)| i)
+        .context("cannot find the day list in days.rs")?;
+    let line_end = last + days_rs[last..].find('\n').context("unterminated line")? + 1;
+    let mut result = days_rs.to_string();
+    result.insert_str(line_end, &entry);
+    Ok(result)
+}
+
+// 读出文件交给 f 修改，只返回修改后的内容，不写回
+fn edit(path: PathBuf, f: impl FnOnce(&str) -> Result<String>) -> Result<(PathBuf, String)> {
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("read {} error", path.display()))?;
+    let edited = f(&content).with_context(|| format!("update {} error", path.display()))?;
+    Ok((path, edited))
+}
+
+/// 从模板生成 `day<N>` crate，并把它注册到工作区和 runner 里
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<()> {
+    let crate_dir = root.join(format!("day{}", day));
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+
+    // 先在内存里算好所有修改，任何一步失败都不会留下半成品
+    let edits = [
+        edit(root.join("Cargo.toml"), |s| register_member(s, day))?,
+        edit(root.join("aoc").join("Cargo.toml"), |s| {
+            register_dependency(s, day)
+        })?,
+        edit(root.join("aoc").join("src").join("days.rs"), |s| {
+            register_day(s, day)
+        })?,
+    ];
+
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::create_dir_all(crate_dir.join("examples"))?;
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        render(CARGO_TEMPLATE, day, title),
+    )?;
+    fs::write(
+        crate_dir.join("src").join("lib.rs"),
+        render(LIB_TEMPLATE, day, title),
+    )?;
+    fs::write(crate_dir.join("examples").join("part1.txt"), "")?;
+
+    for (path, content) in edits {
+        fs::write(&path, content).with_context(|| format!("write {} error", path.display()))?;
+    }
+
+    println!("created {}", crate_dir.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::temp_root;
+
+    #[test]
+    fn test_render() {
+        let lib = render(LIB_TEMPLATE, 8, "Resonant Collinearity");
+        assert!(lib.contains("pub struct Day8;"));
+        assert!(lib.contains("const DAY: u8 = 8;"));
+        assert!(lib.contains("\"Resonant Collinearity\""));
+        assert!(!lib.contains("{{"));
+
+        let lib = render(LIB_TEMPLATE, 8, r#"Resonant "Collinearity" \"#);
+        assert!(lib.contains(r#"const TITLE: &'static str = "Resonant \"Collinearity\" \\";"#));
+    }
+
+    #[test]
+    fn test_new_day_leaves_nothing_behind_on_error() {
+        let root = temp_root("scaffold");
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day8\"]\n";
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        let aoc_manifest = "[dependencies]\n";
+        fs::write(root.join("aoc").join("Cargo.toml"), aoc_manifest).unwrap();
+        let days_rs = "    vec![\n        Day::of::<day1::Day1>(),\n    ]\n";
+        fs::write(root.join("aoc").join("src").join("days.rs"), days_rs).unwrap();
+
+        // day8 已经在 members 里了，注册失败
+        assert!(new_day(&root, 8, "Day 8").is_err());
+        assert!(!root.join("day8").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap(),
+            aoc_manifest
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap(),
+            days_rs
+        );
+
+        // 缺少 days.rs 时也一样
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\"]\n",
+        )
+        .unwrap();
+        fs::remove_file(root.join("aoc").join("src").join("days.rs")).unwrap();
+        assert!(new_day(&root, 8, "Day 8").is_err());
+        assert!(!root.join("day8").exists());
+        assert!(!fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("day8"));
+    }
+
+    #[test]
+    fn test_register_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day1\"]\nresolver = \"2\"\n";
+        let result = register_member(manifest, 2).unwrap();
+        assert!(result.contains("members = [\"aoc\", \"day1\", \"day2\"]"));
+        assert!(register_member(&result, 2).is_err());
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = "[dependencies]\nday1 = {path = \"../day1\"}\n";
+        let result = register_dependency(manifest, 2).unwrap();
+        assert!(result.contains("day2 = { path = \"../day2\" }"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days_rs = "    vec![\n        Day::of::<day1::Day1>(),\n    ]\n";
+        let result = register_day(days_rs, 2).unwrap();
+        assert_eq!(
+            result,
+            "    vec![\n        Day::of::<day1::Day1>(),\n        Day::of::<day2::Day2>(),\n    ]\n"
+        );
+    }
+}
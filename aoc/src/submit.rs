@@ -0,0 +1,276 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{state_dir, Client};
+
+/// 网站对一次提交的回应
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// 提交太频繁，答案本身没有被判定
+    TooRecent,
+    /// 这一部分已经解决过，或者还没解锁
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            Verdict::Correct
+        } else if html.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if html.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if html.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if html.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if html.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// 网站确实判定过这个答案（对或错）
+    fn is_judged(self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix 时间戳（秒）
+    pub submitted_at: u64,
+}
+
+/// 保存在 `.aoc/submissions.json` 里的提交记录
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn default_path(root: &Path) -> PathBuf {
+        state_dir(root).join("submissions.json")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let content =
+            fs::read_to_string(path).with_context(|| format!("read {} error", path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("parse {} error", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n").with_context(|| format!("write {} error", path.display()))
+    }
+
+    /// 根据已知的判定结果拒绝注定错误的答案
+    pub fn guard(&self, day: u8, part: u8, answer: &str) -> Result<()> {
+        let judged = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.verdict.is_judged());
+        let value = answer.parse::<i128>().ok();
+        for s in judged {
+            if s.verdict == Verdict::Correct {
+                bail!(
+                    "day {} part {} is already solved with {}",
+                    day,
+                    part,
+                    s.answer
+                );
+            }
+            if s.answer == answer {
+                bail!("{} was already rejected ({:?})", answer, s.verdict);
+            }
+            let known = s.answer.parse::<i128>().ok();
+            if let (Some(value), Some(known)) = (value, known) {
+                if s.verdict == Verdict::TooHigh && value >= known {
+                    bail!(
+                        "{} is not below {}, which is known to be too high",
+                        answer,
+                        known
+                    );
+                }
+                if s.verdict == Verdict::TooLow && value <= known {
+                    bail!(
+                        "{} is not above {}, which is known to be too low",
+                        answer,
+                        known
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 提交答案并记录结果，提交前先用历史记录检查一遍
+///
+/// `client` 只在检查通过、真的要提交时才调用。
+pub fn submit(
+    client: impl FnOnce() -> Result<Client>,
+    history_path: &Path,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    let mut history = History::load(history_path)?;
+    history.guard(day, part, answer)?;
+
+    let response = client()?.post_answer(day, part, answer)?;
+    let verdict = Verdict::from_response(&response);
+    history.submissions.push(Submission {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        submitted_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    });
+    history.save(history_path)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{client, stub_server, temp_root};
+
+    fn history(records: &[(&str, Verdict)]) -> History {
+        History {
+            submissions: records
+                .iter()
+                .map(|(answer, verdict)| Submission {
+                    day: 1,
+                    part: 1,
+                    answer: answer.to_string(),
+                    verdict: *verdict,
+                    submitted_at: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Verdict::from_response("<p>That's the right answer!</p>"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently"),
+            Verdict::TooRecent
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_guard_rejects_known_wrong_answers() {
+        let h = history(&[
+            ("500", Verdict::TooHigh),
+            ("100", Verdict::TooLow),
+            ("250", Verdict::Wrong),
+        ]);
+        assert!(h.guard(1, 1, "250").is_err());
+        assert!(h.guard(1, 1, "500").is_err());
+        assert!(h.guard(1, 1, "600").is_err());
+        assert!(h.guard(1, 1, "100").is_err());
+        assert!(h.guard(1, 1, "50").is_err());
+        assert!(h.guard(1, 1, "300").is_ok());
+        // 其他部分不受影响
+        assert!(h.guard(1, 2, "600").is_ok());
+    }
+
+    #[test]
+    fn test_guard_ignores_unjudged_and_stops_after_correct() {
+        let h = history(&[("300", Verdict::TooRecent)]);
+        assert!(h.guard(1, 1, "300").is_ok());
+        let h = history(&[("300", Verdict::Correct)]);
+        assert!(h.guard(1, 1, "301").is_err());
+    }
+
+    #[test]
+    fn test_submit_records_history() {
+        let root = temp_root("submit");
+        let path = History::default_path(&root);
+        let (url, requests) = stub_server(
+            "200 OK",
+            "That's not the right answer; your answer is too low.",
+        );
+
+        let verdict = submit(|| Ok(client(&url, &root)), &path, 5, 2, "42").unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/5/answer "));
+        assert!(request.contains("level=2&answer=42"));
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.submissions.len(), 1);
+        assert_eq!(history.submissions[0].verdict, Verdict::TooLow);
+
+        // 已知太低的答案不会再发请求
+        assert!(submit(
+            || Ok(client("http://127.0.0.1:9", &root)),
+            &path,
+            5,
+            2,
+            "40"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_submit_rejected_answer_needs_no_client() {
+        let root = temp_root("submit-guard");
+        let path = History::default_path(&root);
+        history(&[("500", Verdict::TooHigh)]).save(&path).unwrap();
+
+        // 被历史记录拦下时不会去读 session
+        let err = submit(|| unreachable!("client is not needed"), &path, 1, 1, "600").unwrap_err();
+        assert!(err.to_string().contains("too high"));
+
+        assert_eq!(History::load(&path).unwrap().submissions.len(), 1);
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use aoc_common::{is_not_implemented, Answer};
+
+use crate::{answers::Answers, days::Day};
+
+/// 某一部分和记录答案比较后的结果
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    Missing {
+        actual: String,
+    },
+    Error(String),
+    /// 读不到输入文件，例如刚用 `aoc new` 生成、还没有下载输入
+    NoInput(String),
+    NotImplemented,
+}
+
+impl Outcome {
+    pub fn classify(result: Result<Answer>, expected: Option<&str>) -> Self {
+        match (result, expected) {
+            (Ok(answer), Some(expected)) if answer.to_string() == expected => Outcome::Pass,
+            (Ok(answer), Some(expected)) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: answer.to_string(),
+            },
+            (Ok(answer), None) => Outcome::Missing {
+                actual: answer.to_string(),
+            },
+            // 已经记录过答案的部分变成未实现，也算回归
+            (Err(err), Some(expected)) if is_not_implemented(&err) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: err.to_string(),
+            },
+            (Err(err), None) if is_not_implemented(&err) => Outcome::NotImplemented,
+            (Err(err), _) => Outcome::Error(format!("{:#}", err)),
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+
+/// 用默认输入运行某一天的两部分，分别和记录的答案比较
+pub fn check(day: &Day, answers: &Answers) -> [Outcome; 2] {
+    let raw = match (day.data_file)().read() {
+        Ok(raw) => raw,
+        // 只有已经记录了答案的部分读不到输入才算回归
+        Err(err) => {
+            let message = format!("{:#}", anyhow::Error::new(err));
+            return [1, 2].map(|part| match answers.get(day.day, part) {
+                Some(_) => Outcome::Error(message.clone()),
+                None => Outcome::NoInput(message.clone()),
+            });
+        }
+    };
+    let input = (day.parse)(&raw);
+    [1, 2].map(|part| {
+        let result = match &input {
+            Ok(input) => day.solve(part, input),
+            Err(err) => Err(anyhow::anyhow!("parse error: {:#}", err)),
+        };
+        Outcome::classify(result, answers.get(day.day, part))
+    })
+}
+
+/// 运行每一个已实现的部分并和 `answers.toml` 比较，返回是否没有回归
+pub fn verify(days: &[Day], answers: &Answers) -> Result<bool> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        for (part, outcome) in [1, 2].into_iter().zip(check(day, answers)) {
+            let label = format!("day {} part {}", day.day, part);
+            match &outcome {
+                Outcome::Pass => println!("{}: pass", label),
+                Outcome::Fail { expected, actual } => {
+                    println!("{}: FAIL", label);
+                    println!("  - expected: {}", expected);
+                    println!("  + actual:   {}", actual);
+                }
+                Outcome::Missing { actual } => println!("{}: missing (got {})", label, actual),
+                Outcome::Error(err) => println!("{}: FAIL ({})", label, err),
+                Outcome::NoInput(err) => println!("{}: no input ({})", label, err),
+                Outcome::NotImplemented => continue,
+            }
+            if outcome.is_regression() {
+                failed += 1;
+            } else if outcome == Outcome::Pass {
+                passed += 1;
+            } else {
+                missing += 1;
+            }
+        }
+    }
+    println!(
+        "verify: {} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    Ok(failed == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{InputSource, NotImplemented};
+
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            Outcome::classify(Ok(Answer::from(42)), Some("42")),
+            Outcome::Pass
+        );
+        assert_eq!(
+            Outcome::classify(Ok(Answer::from(41)), Some("42")),
+            Outcome::Fail {
+                expected: "42".to_string(),
+                actual: "41".to_string()
+            }
+        );
+        assert_eq!(
+            Outcome::classify(Ok(Answer::from(41)), None),
+            Outcome::Missing {
+                actual: "41".to_string()
+            }
+        );
+        assert_eq!(
+            Outcome::classify(Err(NotImplemented.into()), None),
+            Outcome::NotImplemented
+        );
+        assert!(Outcome::classify(Err(NotImplemented.into()), Some("42")).is_regression());
+        assert!(Outcome::classify(Err(anyhow::anyhow!("boom")), None).is_regression());
+    }
+
+    #[test]
+    fn test_check_without_input() {
+        let day = Day {
+            day: 1,
+            title: "Missing",
+            data_file: || InputSource::File("/no/such/dir/data.txt".into()),
+            parse: |_| unreachable!(),
+            part1: |_| unreachable!(),
+            part2: |_| unreachable!(),
+        };
+
+        let [part1, part2] = check(&day, &Answers::default());
+        assert!(matches!(&part1, Outcome::NoInput(err) if err.contains("/no/such/dir/data.txt")));
+        assert!(!part1.is_regression());
+        assert!(matches!(part2, Outcome::NoInput(_)));
+
+        // 记录过答案却读不到输入，算回归
+        let answers = Answers::parse("[day1]\npart1 = 11\n").unwrap();
+        let [part1, part2] = check(&day, &answers);
+        assert!(matches!(part1, Outcome::Error(_)));
+        assert!(matches!(part2, Outcome::NoInput(_)));
+    }
+}
@@ -0,0 +1,8 @@
+[package]
+name = "day{{day}}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = {workspace = true}
+aoc-common = {workspace = true}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use aoc_common::{data_file, Answer, InputSource, NotImplemented, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+    const TITLE: &'static str = {{title}};
+
+    type Input = Vec<String>;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        Err(NotImplemented.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day{{day}};
+        #[ignore = "fill in examples/part1.txt and the expected answer"]
+        test_example_part1: part1("examples/part1.txt") => 0,
+    }
+}
@@ -1,9 +1,12 @@
-mod positions;
+pub mod positions;
 use std::collections::HashMap;
 
 use anyhow::Result;
+use aoc_common::{data_file, Answer, InputSource, Solution};
 use positions::Positions;
 
+pub struct Day1;
+
 fn total_distance(data: (Vec<i64>, Vec<i64>)) -> i64 {
     data.0.iter().enumerate().fold(0, |res, (i, v)| {
         let right_value = data.1[i];
@@ -36,13 +39,36 @@ fn similarity_score(data: (Vec<i64>, Vec<i64>)) -> i64 {
     })
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let positions = Positions::try_load().await?;
-    // part one
-    println!("{:?}", total_distance(positions.sort_to_vec()));
-    // part two
-    println!("{:?}", similarity_score(positions.sort_to_vec()));
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Positions;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(positions: &Self::Input) -> Result<Answer> {
+        Ok(total_distance(positions.sort_to_vec()).into())
+    }
+
+    fn part2(positions: &Self::Input) -> Result<Answer> {
+        Ok(similarity_score(positions.sort_to_vec()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day1;
+        test_example_part1: part1("examples/part1.txt") => 11,
+        test_example_part2: part2("examples/part1.txt") => 31,
+    }
 }
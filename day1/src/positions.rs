@@ -1,10 +1,4 @@
-use std::{path::Path, str::FromStr};
-
-use anyhow::Result;
-use tokio::{
-    fs::File,
-    io::{AsyncBufReadExt, BufReader},
-};
+use std::str::FromStr;
 
 use std::fmt::Debug;
 
@@ -46,22 +40,20 @@ impl FromStr for Position {
     }
 }
 
-impl Positions {
-    pub async fn try_load() -> Result<Self> {
-        let project_root = env!("CARGO_MANIFEST_DIR");
-        let data_path = Path::new(&project_root).join("data.txt");
-        let f = File::open(data_path).await?;
-        let reader = BufReader::new(f);
-        let mut lines = reader.lines();
+impl FromStr for Positions {
+    type Err = PositionsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut positions: Vec<Position> = Vec::new();
 
-        while let Some(line) = lines.next_line().await? {
-            positions.push(Position::from_str(&line)?);
+        for line in s.lines() {
+            positions.push(Position::from_str(line)?);
         }
 
         Ok(Positions(positions))
     }
+}
 
+impl Positions {
     pub fn sort_to_vec(&self) -> (Vec<i64>, Vec<i64>) {
         let mut left_rev = Vec::new();
         let mut right_rev = Vec::new();
@@ -1,29 +1,21 @@
-use std::{
-    fs::File,
-    io::{self, BufRead},
-    path::Path,
-};
+use anyhow::Result;
+use aoc_common::{data_file, Answer, InputSource, Solution};
 
-fn read_data() -> io::Result<Vec<Vec<u32>>> {
-    let mut result = vec![];
-    let project_root = env!("CARGO_MANIFEST_DIR");
-    let file_path = Path::new(&project_root).join("data.txt");
-
-    let file = File::open(&file_path)?;
+pub struct Day2;
 
-    let reader = io::BufReader::new(file);
-    for line in reader.lines() {
-        let line = line?;
-        let data: Vec<u32> = line
+fn parse_reports(content: &str) -> Result<Vec<Vec<u32>>> {
+    let mut result = vec![];
+    for line in content.lines() {
+        let data = line
             .split_whitespace()
-            .map(|str| str.parse::<u32>().expect("parse to u32 error"))
-            .collect();
+            .map(|str| str.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()?;
         result.push(data);
     }
     Ok(result)
 }
 
-fn is_sorted(vec: &Vec<u32>) -> (bool, bool) {
+fn is_sorted(vec: &[u32]) -> (bool, bool) {
     let mut is_ascending = true;
     let mut is_descending = true;
 
@@ -41,11 +33,11 @@ fn is_sorted(vec: &Vec<u32>) -> (bool, bool) {
     (is_ascending, is_descending)
 }
 
-fn is_within_range(vec: &Vec<u32>) -> bool {
+fn is_within_range(vec: &[u32]) -> bool {
     // 遍历向量中的相邻元素
     for i in 0..vec.len() - 1 {
         let diff = (vec[i] as i32 - vec[i + 1] as i32).abs(); // 计算绝对差值
-        if diff < 1 || diff > 3 {
+        if !(1..=3).contains(&diff) {
             // 检查差值是否在范围内
             return false; // 如果不在范围内，返回 false
         }
@@ -53,19 +45,19 @@ fn is_within_range(vec: &Vec<u32>) -> bool {
     true // 如果所有相邻元素都符合条件，返回 true
 }
 
-fn part_one(data: Vec<Vec<u32>>) -> usize {
+fn part_one(data: &[Vec<u32>]) -> usize {
     data.iter()
         .filter(|v| {
-            let (is_asc, is_des) = is_sorted(*v);
+            let (is_asc, is_des) = is_sorted(v);
             if is_asc || is_des {
-                return is_within_range(*v);
+                return is_within_range(v);
             }
-            return false;
+            false
         })
         .count()
 }
 
-fn can_be_sorted_by_removing_one_or_none(levels: &Vec<u32>) -> bool {
+fn can_be_sorted_by_removing_one_or_none(levels: &[u32]) -> bool {
     fn is_valid_sequence(arr: &[u32]) -> bool {
         if arr.is_empty() {
             return true;
@@ -74,12 +66,12 @@ fn can_be_sorted_by_removing_one_or_none(levels: &Vec<u32>) -> bool {
         // 检查是否递增或递减，同时检查相邻数字的差值
         let ascending = arr.windows(2).all(|w| {
             let diff = (w[1] as i32 - w[0] as i32).abs();
-            w[0] <= w[1] && diff >= 1 && diff <= 3
+            w[0] <= w[1] && (1..=3).contains(&diff)
         });
 
         let descending = arr.windows(2).all(|w| {
             let diff = (w[1] as i32 - w[0] as i32).abs();
-            w[0] >= w[1] && diff >= 1 && diff <= 3
+            w[0] >= w[1] && (1..=3).contains(&diff)
         });
 
         ascending || descending
@@ -104,25 +96,45 @@ fn can_be_sorted_by_removing_one_or_none(levels: &Vec<u32>) -> bool {
     false
 }
 
-fn part_two(data: Vec<Vec<u32>>) -> usize {
+fn part_two(data: &[Vec<u32>]) -> usize {
     data.iter()
         .filter(|v| can_be_sorted_by_removing_one_or_none(v))
         .count()
 }
 
-fn main() -> io::Result<()> {
-    let data = read_data()?;
-    let result = part_one(data.clone());
-    println!("part_one : {}", result);
-    let result = part_two(data);
-    println!("part_two : {}", result);
-    Ok(())
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<u32>>;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Answer> {
+        Ok(part_one(reports).into())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Answer> {
+        Ok(part_two(reports).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    aoc_common::example_tests! {
+        Day2;
+        test_example_part1: part1("examples/part1.txt") => 2,
+        test_example_part2: part2("examples/part1.txt") => 4,
+    }
+
     #[test]
     fn test_part_two() {
         let data = vec![
@@ -134,17 +146,17 @@ mod tests {
             vec![1, 3, 6, 7, 9], // true - already sorted (ascending)
         ];
 
-        let result = part_two(data);
+        let result = part_two(&data);
         assert_eq!(result, 4); // 应该有4个有效序列
     }
 
     #[test]
     fn test_individual_sequences() {
-        assert!(can_be_sorted_by_removing_one_or_none(&vec![7, 6, 4, 2, 1])); // true
-        assert!(!can_be_sorted_by_removing_one_or_none(&vec![1, 2, 7, 8, 9])); // false
-        assert!(!can_be_sorted_by_removing_one_or_none(&vec![9, 7, 6, 2, 1])); // false
-        assert!(can_be_sorted_by_removing_one_or_none(&vec![1, 3, 2, 4, 5])); // true
-        assert!(can_be_sorted_by_removing_one_or_none(&vec![8, 6, 4, 4, 1])); // true
-        assert!(can_be_sorted_by_removing_one_or_none(&vec![1, 3, 6, 7, 9])); // true
+        assert!(can_be_sorted_by_removing_one_or_none(&[7, 6, 4, 2, 1])); // true
+        assert!(!can_be_sorted_by_removing_one_or_none(&[1, 2, 7, 8, 9])); // false
+        assert!(!can_be_sorted_by_removing_one_or_none(&[9, 7, 6, 2, 1])); // false
+        assert!(can_be_sorted_by_removing_one_or_none(&[1, 3, 2, 4, 5])); // true
+        assert!(can_be_sorted_by_removing_one_or_none(&[8, 6, 4, 4, 1])); // true
+        assert!(can_be_sorted_by_removing_one_or_none(&[1, 3, 6, 7, 9])); // true
     }
 }
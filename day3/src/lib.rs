@@ -0,0 +1,90 @@
+use anyhow::Result;
+use aoc_common::{data_file, Answer, InputSource, Solution};
+use regex::Regex;
+
+pub struct Day3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+fn parse_instructions(content: &str) -> Result<Vec<Instruction>> {
+    let mut instructions = vec![];
+    // 匹配 do()、don't() 和 mul(num1,num2)
+    let re = Regex::new(r"(do\(\))|(don't\(\))|mul\((\d+),(\d+)\)")?;
+
+    for cap in re.captures_iter(content) {
+        if cap.get(1).is_some() {
+            instructions.push(Instruction::Do);
+        } else if cap.get(2).is_some() {
+            instructions.push(Instruction::Dont);
+        } else {
+            let num1 = cap[3].parse::<i32>()?;
+            let num2 = cap[4].parse::<i32>()?;
+            instructions.push(Instruction::Mul(num1, num2));
+        }
+    }
+
+    Ok(instructions)
+}
+
+fn part1(instructions: &[Instruction]) -> i32 {
+    instructions.iter().fold(0, |acc, ins| match ins {
+        Instruction::Mul(x, y) => acc + x * y,
+        _ => acc,
+    })
+}
+
+fn part2(instructions: &[Instruction]) -> i32 {
+    let mut is_disabled = false;
+    let mut result = 0;
+    for ins in instructions {
+        match ins {
+            Instruction::Do => is_disabled = false,
+            Instruction::Dont => is_disabled = true,
+            Instruction::Mul(x, y) => {
+                if !is_disabled {
+                    result += x * y;
+                }
+            }
+        }
+    }
+    result
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part1(instructions).into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part2(instructions).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day3;
+        test_example_part1: part1("examples/part1.txt") => 161,
+        test_example_part2: part2("examples/part2.txt") => 48,
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use aoc_common::{data_file, Answer, Direction8, Grid, InputSource, Point, Solution, Vector};
+
+pub struct Day4;
+
+// 从 start 开始沿 dir 方向读出的字符是否正好是 target
+fn matches_ray(grid: &Grid<char>, start: Point, dir: Direction8, target: &str) -> bool {
+    let mut ray = grid.ray(start, dir).map(|(_, c)| *c);
+    target.chars().all(|t| ray.next() == Some(t))
+}
+
+fn check_pattern(grid: &Grid<char>, center: Point) -> bool {
+    // 检查中心必须是 'A'
+    if grid.get(center) != Some(&'A') {
+        return false;
+    }
+
+    // 获取四个角的字符，越界时为 None
+    let corner = |dx, dy| grid.get(center + Vector::new(dx, dy)).copied();
+    let is_mas = |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+
+    // 左上到右下、右上到左下两条对角线都必须是有效的 MAS 或 SAM
+    is_mas(corner(-1, -1), corner(1, 1)) && is_mas(corner(1, -1), corner(-1, 1))
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    // 从每个 X 出发，检查八个方向
+    grid.iter()
+        .filter(|(_, c)| **c == 'X')
+        .map(|(p, _)| {
+            Direction8::ALL
+                .into_iter()
+                .filter(|&dir| matches_ray(grid, p, dir, "XMAS"))
+                .count()
+        })
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    grid.points().filter(|&p| check_pattern(grid, p)).count()
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(part2(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day4;
+        test_example_part1: part1("examples/part1.txt") => 18,
+        test_example_part2: part2("examples/part1.txt") => 9,
+    }
+
+    #[test]
+    fn test_part1() {
+        let grid: Grid<char> = [
+            "MMMSXXMASM",
+            "MSAMXMSMSA",
+            "AMXSXMAAMM",
+            "MSAMASMSMX",
+            "XMASAMXAMM",
+            "XXAMMXXAMA",
+            "SMSMSASXSS",
+            "SAXAMASAAA",
+            "MAMMMXMMMM",
+            "MXMXAXMASX",
+        ]
+        .join("\n")
+        .parse()
+        .unwrap();
+        let count = part1(&grid);
+        assert_eq!(count, 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let grid: Grid<char> = [
+            ".M.S......",
+            "..A..MSMS.",
+            ".M.S.MAA..",
+            "..A.ASMSM.",
+            ".M.S.M....",
+            "..........",
+            "S.S.S.S.S.",
+            ".A.A.A.A..",
+            "M.M.M.M.M.",
+            "..........",
+        ]
+        .join("\n")
+        .parse()
+        .unwrap();
+        let count = part2(&grid);
+        assert_eq!(count, 9);
+    }
+}
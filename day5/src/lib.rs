@@ -0,0 +1,147 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{data_file, Answer, InputSource, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day5;
+
+pub struct PrintQueue {
+    rules: HashMap<i32, HashSet<i32>>,
+    sequences: Vec<Vec<i32>>,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = PrintQueue;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_queue(input)
+    }
+
+    fn part1(queue: &Self::Input) -> Result<Answer> {
+        Ok(part1(queue).into())
+    }
+
+    fn part2(queue: &Self::Input) -> Result<Answer> {
+        Ok(part2(queue).into())
+    }
+}
+
+fn parse_queue(input: &str) -> Result<PrintQueue> {
+    let mut rules: HashMap<i32, HashSet<i32>> = HashMap::new();
+    let mut sequences: Vec<Vec<i32>> = Vec::new();
+
+    // 解析输入
+    let (rules_part, sequences_part) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("missing blank line between rules and updates"))?;
+
+    // 解析规则
+    for line in rules_part.lines() {
+        let (before, after) = line
+            .split_once('|')
+            .ok_or_else(|| anyhow!("invalid rule: {}", line))?;
+        rules
+            .entry(before.parse()?)
+            .or_default()
+            .insert(after.parse()?);
+    }
+
+    // 解析序列
+    for line in sequences_part.lines() {
+        let seq = line
+            .split(',')
+            .map(|s| s.parse())
+            .collect::<Result<Vec<i32>, _>>()?;
+        sequences.push(seq);
+    }
+
+    Ok(PrintQueue { rules, sequences })
+}
+
+fn part1(queue: &PrintQueue) -> i32 {
+    // 检查每个序列并计算结果
+    queue
+        .sequences
+        .iter()
+        .filter(|seq| is_valid_sequence(seq, &queue.rules))
+        .map(|seq| seq[seq.len() / 2])
+        .sum()
+}
+
+fn part2(queue: &PrintQueue) -> i32 {
+    // 找出错误排序的序列并重新排序
+    queue
+        .sequences
+        .iter()
+        .filter(|seq| !is_valid_sequence(seq, &queue.rules))
+        .map(|seq| {
+            let mut seq = seq.clone();
+            sort_sequence(&mut seq, &queue.rules);
+            seq[seq.len() / 2]
+        })
+        .sum()
+}
+
+fn is_valid_sequence(sequence: &[i32], rules: &HashMap<i32, HashSet<i32>>) -> bool {
+    for (i, &num1) in sequence.iter().enumerate() {
+        for &num2 in &sequence[i + 1..] {
+            // 检查 num1 -> num2 的规则
+            if let Some(deps) = rules.get(&num2) {
+                if deps.contains(&num1) {
+                    return false; // 违反规则
+                }
+            }
+            // 检查 num2 -> num1 的规则
+            if let Some(deps) = rules.get(&num1) {
+                if deps.contains(&num2) {
+                    continue; // 符合规则
+                }
+            }
+        }
+    }
+    true
+}
+
+fn sort_sequence(sequence: &mut [i32], rules: &HashMap<i32, HashSet<i32>>) {
+    // 使用冒泡排序，根据规则比较相邻元素
+    let n = sequence.len();
+    for i in 0..n {
+        for j in 0..n - i - 1 {
+            if should_swap(&sequence[j], &sequence[j + 1], rules) {
+                sequence.swap(j, j + 1);
+            }
+        }
+    }
+}
+
+fn should_swap(a: &i32, b: &i32, rules: &HashMap<i32, HashSet<i32>>) -> bool {
+    // 检查是否需要交换两个元素
+    if let Some(deps) = rules.get(a) {
+        if deps.contains(b) {
+            return true;
+        }
+    }
+    if let Some(deps) = rules.get(b) {
+        if deps.contains(a) {
+            return false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day5;
+        test_example_part1: part1("examples/part1.txt") => 143,
+        test_example_part2: part2("examples/part1.txt") => 123,
+    }
+}
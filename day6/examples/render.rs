@@ -0,0 +1,104 @@
+//! 把守卫的路线画出来，排查答案不对的时候用
+//!
+//! ```bash
+//! cargo run -p day6 --example render -- --obstruction 3,6 --color --svg path.svg
+//! ```
+
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::{InputSource, Point, Solution};
+use clap::Parser;
+use day6::{
+    lab::Patrol,
+    render::{self, Trace},
+    walk::Outcome,
+    Day6,
+};
+
+#[derive(Parser)]
+struct Args {
+    /// 输入文件路径，`-` 表示从标准输入读取；默认是 day6/data.txt
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// 额外放一个障碍，格式为 `x,y`
+    #[arg(short, long, value_parser = parse_point)]
+    obstruction: Option<Point>,
+
+    /// 用颜色标出循环部分
+    #[arg(long)]
+    color: bool,
+
+    /// 同时把路线写成 SVG 文件
+    #[arg(long)]
+    svg: Option<PathBuf>,
+}
+
+fn parse_point(s: &str) -> Result<Point> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| anyhow!("expected x,y, got {:?}", s))?;
+    Ok(Point::new(x.trim().parse()?, y.trim().parse()?))
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let source = args.input.unwrap_or_else(Day6::data_file);
+    let lab = Day6::parse(&source.read()?)?;
+
+    if let Some(p) = args.obstruction {
+        ensure!(
+            lab.map.get(p) == Some(&'.') && lab.guards.iter().all(|g| g.position != p),
+            "obstruction {:?} is not an empty cell",
+            p
+        );
+    }
+
+    let traces: Vec<Trace> = lab
+        .guards
+        .iter()
+        .map(|guard| Trace::record(&lab, guard, args.obstruction))
+        .collect();
+
+    print!(
+        "{}",
+        render::ascii(&lab, args.obstruction, &traces, args.color)
+    );
+    // 按加了障碍之后的路线统计和其他守卫重叠的格子
+    let patrol = Patrol::from_visited(
+        traces
+            .iter()
+            .map(|trace| trace.steps.iter().map(|step| step.position).collect())
+            .collect(),
+    );
+    for (trace, visited) in traces.iter().zip(&patrol.visited) {
+        let outcome = match trace.outcome {
+            Outcome::Exited => "exited".to_string(),
+            Outcome::Looped {
+                cycle_start,
+                cycle_len,
+            } => format!("looped from step {} every {} steps", cycle_start, cycle_len),
+        };
+        println!(
+            "guard at ({}, {}): {} steps, {} cells shared with other guards, {}",
+            trace.guard.position.x,
+            trace.guard.position.y,
+            trace.steps.len(),
+            visited.intersection(&patrol.overlaps).count(),
+            outcome
+        );
+    }
+    if traces.len() > 1 {
+        println!(
+            "{} cells visited by more than one guard",
+            patrol.overlaps.len()
+        );
+    }
+
+    if let Some(path) = &args.svg {
+        fs::write(path, render::svg(&lab, args.obstruction, &traces))?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
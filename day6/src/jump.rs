@@ -0,0 +1,171 @@
+use aoc_common::{Direction, Grid, Point};
+
+/// 跳表：记录从每个格子沿每个方向一直走，会停在哪个格子上
+///
+/// 守卫不用一格一格地走，可以直接跳到下一次右转的位置。临时放一个障碍时只需要更新会撞上它的几条射线。
+pub struct JumpTable {
+    blocked: Grid<bool>,
+    // 沿某个方向走到被挡住时停下的格子，走出地图为 None
+    stops: Grid<[Option<Point>; 4]>,
+    // 找循环时每个格子按方向记下的转弯，以及用完后需要清空的格子
+    seen: Grid<u8>,
+    touched: Vec<Point>,
+}
+
+impl JumpTable {
+    pub fn new(map: &Grid<char>) -> Self {
+        let blocked = map.map(|&c| c == '#');
+        let mut stops = map.filled_like([None; 4]);
+        let points: Vec<Point> = map.points().collect();
+
+        for dir in Direction::ALL {
+            let mut fill = |p: Point| {
+                stops[p][dir as usize] = match blocked.step(p, dir) {
+                    None => None,
+                    Some(next) if blocked[next] => Some(p),
+                    Some(next) => stops[next][dir as usize],
+                };
+            };
+            // 按行优先顺序，向上、向左时前面的格子已经算好；向下、向右要倒过来算
+            if matches!(dir, Direction::Up | Direction::Left) {
+                points.iter().for_each(|&p| fill(p));
+            } else {
+                points.iter().rev().for_each(|&p| fill(p));
+            }
+        }
+
+        JumpTable {
+            seen: map.filled_like(0),
+            touched: Vec::new(),
+            blocked,
+            stops,
+        }
+    }
+
+    /// 从 `from` 朝 `dir` 走，停下的格子；走出地图时为 `None`
+    pub fn jump(&self, from: Point, dir: Direction) -> Option<Point> {
+        self.stops[from][dir as usize]
+    }
+
+    /// 在空格子 `p` 上临时放一个障碍，同一时间只能放一个
+    pub fn insert(&mut self, p: Point) {
+        self.blocked[p] = true;
+        for dir in Direction::ALL {
+            let stop = self.blocked.step(p, dir.reverse());
+            self.fill_behind(p, dir, stop);
+        }
+    }
+
+    /// 拿掉 `insert` 放的障碍
+    pub fn remove(&mut self, p: Point) {
+        self.blocked[p] = false;
+        // p 自己的记录从来没改过，后面的格子和它停在同一个地方
+        for dir in Direction::ALL {
+            let stop = self.stops[p][dir as usize];
+            self.fill_behind(p, dir, stop);
+        }
+    }
+
+    // 更新沿 dir 走会经过 p 的那些格子（到上一个障碍为止）
+    fn fill_behind(&mut self, p: Point, dir: Direction, stop: Option<Point>) {
+        for (q, _) in self
+            .blocked
+            .ray(p, dir.reverse())
+            .skip(1)
+            .take_while(|(_, &blocked)| !blocked)
+        {
+            self.stops[q][dir as usize] = stop;
+        }
+    }
+
+    /// 守卫从 `start` 朝 `dir` 出发会不会陷入循环
+    ///
+    /// 只记录转弯处的 (位置, 方向)，重复出现就是循环。
+    pub fn loops(&mut self, start: Point, dir: Direction) -> bool {
+        let (mut pos, mut dir) = (start, dir);
+        let looped = loop {
+            let Some(stop) = self.jump(pos, dir) else {
+                break false;
+            };
+            let mask = 1 << dir as u8;
+            if self.seen[stop] & mask != 0 {
+                break true;
+            }
+            if self.seen[stop] == 0 {
+                self.touched.push(stop);
+            }
+            self.seen[stop] |= mask;
+            pos = stop;
+            dir = dir.turn_right();
+        };
+
+        for p in self.touched.drain(..) {
+            self.seen[p] = 0;
+        }
+        looped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::embedded_input;
+
+    use super::*;
+    use crate::walk::{GuardWalk, Outcome};
+
+    fn example() -> Grid<char> {
+        embedded_input!("examples/part1.txt")
+            .read()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_jump() {
+        let map = example();
+        let table = JumpTable::new(&map);
+
+        assert_eq!(
+            table.jump(Point::new(4, 6), Direction::Up),
+            Some(Point::new(4, 1))
+        );
+        assert_eq!(
+            table.jump(Point::new(4, 1), Direction::Right),
+            Some(Point::new(8, 1))
+        );
+        assert_eq!(table.jump(Point::new(0, 0), Direction::Left), None);
+        assert_eq!(
+            table.jump(Point::new(8, 1), Direction::Right),
+            Some(Point::new(8, 1))
+        );
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let map = example();
+        let fresh = JumpTable::new(&map);
+        let mut table = JumpTable::new(&map);
+        let start = map.position_of(&'^').unwrap();
+
+        for p in map.points().filter(|&p| map[p] == '.') {
+            table.insert(p);
+            let walk = GuardWalk::new(&map, start, Direction::Up)
+                .with_obstruction(p)
+                .finish();
+            assert_eq!(
+                table.loops(start, Direction::Up),
+                matches!(walk, Outcome::Looped { .. }),
+                "obstruction at {:?}",
+                p
+            );
+            table.remove(p);
+        }
+
+        for p in map.points() {
+            for dir in Direction::ALL {
+                assert_eq!(table.jump(p, dir), fresh.jump(p, dir));
+            }
+        }
+    }
+}
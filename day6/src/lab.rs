@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use aoc_common::{Direction, Grid, GridError, Point};
+use thiserror::Error;
+
+use crate::walk::GuardWalk;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum LabError {
+    #[error("parse lab map error")]
+    Grid(#[from] GridError),
+
+    #[error("no guard found in lab map")]
+    NoGuard,
+}
+
+/// 守卫的起点和朝向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Guard {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+impl Guard {
+    /// `^ > v <` 分别表示朝上、右、下、左的守卫
+    pub fn direction_of(glyph: char) -> Option<Direction> {
+        match glyph {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// 画地图时守卫的符号，和 `direction_of` 相反
+    pub fn glyph(&self) -> char {
+        match self.direction {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// 实验室地图，守卫站的格子已经换成空地
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lab {
+    pub map: Grid<char>,
+    pub guards: Vec<Guard>,
+}
+
+/// 所有守卫各自走完之后的结果
+#[derive(Debug)]
+pub struct Patrol {
+    /// 每个守卫走过的位置，顺序和 `Lab::guards` 一致
+    pub visited: Vec<HashSet<Point>>,
+    /// 被两个及以上守卫走过的位置
+    pub overlaps: HashSet<Point>,
+}
+
+impl Patrol {
+    /// 由每个守卫走过的位置算出重叠的部分
+    pub fn from_visited(visited: Vec<HashSet<Point>>) -> Self {
+        let mut counts: HashMap<Point, usize> = HashMap::new();
+        for p in visited.iter().flatten() {
+            *counts.entry(*p).or_default() += 1;
+        }
+        let overlaps = counts
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(p, _)| p)
+            .collect();
+
+        Patrol { visited, overlaps }
+    }
+
+    /// 至少一个守卫走过的位置
+    pub fn union(&self) -> HashSet<Point> {
+        self.visited.iter().flatten().copied().collect()
+    }
+}
+
+impl Lab {
+    pub fn walk(&self, guard: &Guard) -> GuardWalk<'_> {
+        GuardWalk::new(&self.map, guard.position, guard.direction)
+    }
+
+    /// 每个守卫互不影响地各走一遍
+    pub fn patrol(&self) -> Patrol {
+        Patrol::from_visited(
+            self.guards
+                .iter()
+                .map(|guard| self.walk(guard).map(|step| step.position).collect())
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for Lab {
+    type Err = LabError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Grid::parse_with(s, |ch| {
+            (matches!(ch, '.' | '#') || Guard::direction_of(ch).is_some()).then_some(ch)
+        })?;
+
+        let guards: Vec<Guard> = map
+            .iter()
+            .filter_map(|(position, &ch)| {
+                Guard::direction_of(ch).map(|direction| Guard {
+                    position,
+                    direction,
+                })
+            })
+            .collect();
+        if guards.is_empty() {
+            return Err(LabError::NoGuard);
+        }
+
+        for guard in &guards {
+            map[guard.position] = '.';
+        }
+        Ok(Lab { map, guards })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let lab: Lab = [".#.", ".>.", "..v"].join("\n").parse().unwrap();
+        assert_eq!(
+            lab.guards,
+            vec![
+                Guard {
+                    position: Point::new(1, 1),
+                    direction: Direction::Right
+                },
+                Guard {
+                    position: Point::new(2, 2),
+                    direction: Direction::Down
+                },
+            ]
+        );
+        assert_eq!(lab.map.to_string(), ".#.\n...\n...\n");
+
+        assert_eq!("..\n.#".parse::<Lab>(), Err(LabError::NoGuard));
+        assert!(matches!(
+            "^.\n.x".parse::<Lab>(),
+            Err(LabError::Grid(GridError::InvalidCell { ch: 'x', .. }))
+        ));
+    }
+
+    #[test]
+    fn test_patrol() {
+        let lab: Lab = [".#...", ".....", ".^.<.", "....."]
+            .join("\n")
+            .parse()
+            .unwrap();
+        let patrol = lab.patrol();
+
+        assert_eq!(patrol.visited[0].len(), 5);
+        assert_eq!(patrol.visited[1].len(), 4);
+        assert_eq!(patrol.overlaps, HashSet::from([Point::new(1, 2)]));
+        assert_eq!(patrol.union().len(), 8);
+    }
+}
@@ -0,0 +1,73 @@
+pub mod jump;
+pub mod lab;
+pub mod render;
+pub mod walk;
+
+use anyhow::Result;
+use aoc_common::{data_file, Answer, InputSource, Point, Solution};
+use jump::JumpTable;
+use lab::Lab;
+use std::collections::HashSet;
+
+pub struct Day6;
+
+// 至少一个守卫走过的位置数
+fn solve_part1(lab: &Lab) -> usize {
+    lab.patrol().union().len()
+}
+
+// 能让至少一个守卫陷入循环的障碍位置数
+fn solve_part2(lab: &Lab) -> usize {
+    let starts: HashSet<Point> = lab.guards.iter().map(|guard| guard.position).collect();
+    let mut table = JumpTable::new(&lab.map);
+
+    // 只有守卫原路径上的格子放障碍才会改变路线，守卫站着的格子不能放
+    lab.patrol()
+        .union()
+        .into_iter()
+        .filter(|p| !starts.contains(p))
+        .filter(|&p| {
+            table.insert(p);
+            let looped = lab
+                .guards
+                .iter()
+                .any(|guard| table.loops(guard.position, guard.direction));
+            table.remove(p);
+            looped
+        })
+        .count()
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Lab;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(lab: &Self::Input) -> Result<Answer> {
+        Ok(solve_part1(lab).into())
+    }
+
+    fn part2(lab: &Self::Input) -> Result<Answer> {
+        Ok(solve_part2(lab).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day6;
+        test_example_part1: part1("examples/part1.txt") => 41,
+        test_example_part2: part2("examples/part1.txt") => 6,
+    }
+}
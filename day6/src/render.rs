@@ -0,0 +1,223 @@
+use std::collections::HashSet;
+
+use aoc_common::{Direction, Point};
+
+use crate::lab::{Guard, Lab};
+use crate::walk::{Outcome, Step};
+
+// SVG 里每个格子的边长
+const CELL: i64 = 10;
+
+/// 一个守卫完整走一遍的记录
+#[derive(Debug)]
+pub struct Trace {
+    pub guard: Guard,
+    pub steps: Vec<Step>,
+    pub outcome: Outcome,
+}
+
+impl Trace {
+    pub fn record(lab: &Lab, guard: &Guard, obstruction: Option<Point>) -> Self {
+        let mut walk = lab.walk(guard);
+        if let Some(p) = obstruction {
+            walk = walk.with_obstruction(p);
+        }
+        let steps = walk.by_ref().collect();
+        Trace {
+            guard: *guard,
+            steps,
+            outcome: walk.finish(),
+        }
+    }
+
+    /// 循环部分的步，走出地图时为空
+    pub fn cycle(&self) -> &[Step] {
+        match self.outcome {
+            Outcome::Exited => &[],
+            Outcome::Looped {
+                cycle_start,
+                cycle_len,
+            } => &self.steps[cycle_start..cycle_start + cycle_len],
+        }
+    }
+}
+
+/// 按谜题页面的样子画出路线：竖着走 `|`，横着走 `-`，转弯或交叉 `+`，额外的障碍 `O`
+///
+/// `color` 为真时用 ANSI 红色标出循环部分。
+pub fn ascii(lab: &Lab, obstruction: Option<Point>, traces: &[Trace], color: bool) -> String {
+    // 每个格子记下竖着走过 (1) 和横着走过 (2)
+    let mut axes = lab.map.filled_like(0u8);
+    for step in traces.iter().flat_map(|trace| &trace.steps) {
+        axes[step.position] |= match step.direction {
+            Direction::Up | Direction::Down => 1,
+            Direction::Left | Direction::Right => 2,
+        };
+    }
+
+    let mut canvas = lab.map.clone();
+    for (p, &axis) in axes.iter() {
+        canvas[p] = match axis {
+            1 => '|',
+            2 => '-',
+            3 => '+',
+            _ => canvas[p],
+        };
+    }
+    for trace in traces {
+        canvas[trace.guard.position] = trace.guard.glyph();
+    }
+    if let Some(cell) = obstruction.and_then(|p| canvas.get_mut(p)) {
+        *cell = 'O';
+    }
+
+    let cycle: HashSet<Point> = traces
+        .iter()
+        .flat_map(|trace| trace.cycle())
+        .map(|step| step.position)
+        .collect();
+
+    let mut out = String::new();
+    for (p, &ch) in canvas.iter() {
+        if color && cycle.contains(&p) {
+            out.push_str(&format!("\x1b[1;31m{}\x1b[0m", ch));
+        } else {
+            out.push(ch);
+        }
+        if p.x as usize == canvas.width() - 1 {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// 格子中心在 SVG 里的坐标
+fn center(p: Point) -> String {
+    format!("{},{}", p.x * CELL + CELL / 2, p.y * CELL + CELL / 2)
+}
+
+fn polyline(steps: &[Step], stroke: &str, width: i64) -> String {
+    let mut points: Vec<Point> = steps.iter().map(|step| step.position).collect();
+    points.dedup();
+    let points: Vec<String> = points.into_iter().map(center).collect();
+    format!(
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+        points.join(" "),
+        stroke,
+        width
+    )
+}
+
+fn square(p: Point, fill: &str) -> String {
+    format!(
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        p.x * CELL,
+        p.y * CELL,
+        CELL,
+        CELL,
+        fill
+    )
+}
+
+/// 把同样的内容画成 SVG，循环部分用加粗的红线标出
+pub fn svg(lab: &Lab, obstruction: Option<Point>, traces: &[Trace]) -> String {
+    let width = lab.map.width() as i64 * CELL;
+    let height = lab.map.height() as i64 * CELL;
+
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+        width, height
+    );
+    out.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        width, height
+    ));
+    for (p, _) in lab.map.iter().filter(|(_, &ch)| ch == '#') {
+        out.push_str(&square(p, "#333"));
+    }
+    if let Some(p) = obstruction {
+        out.push_str(&square(p, "orange"));
+    }
+
+    for trace in traces {
+        out.push_str(&polyline(&trace.steps, "steelblue", 2));
+        let cycle = trace.cycle();
+        if let Some(first) = cycle.first() {
+            // 回到循环开头，把圈画闭合
+            let closed: Vec<Step> = cycle.iter().chain([first]).copied().collect();
+            out.push_str(&polyline(&closed, "crimson", 3));
+        }
+        out.push_str(&format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"green\"/>\n",
+            trace.guard.position.x * CELL + CELL / 2,
+            trace.guard.position.y * CELL + CELL / 2,
+            CELL / 3
+        ));
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::embedded_input;
+
+    use super::*;
+
+    fn example() -> Lab {
+        embedded_input!("examples/part1.txt")
+            .read()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    fn record(lab: &Lab, obstruction: Option<Point>) -> Vec<Trace> {
+        lab.guards
+            .iter()
+            .map(|guard| Trace::record(lab, guard, obstruction))
+            .collect()
+    }
+
+    #[test]
+    fn test_ascii_loop() {
+        let lab = example();
+        let obstruction = Some(Point::new(3, 6));
+        let traces = record(&lab, obstruction);
+
+        // 和谜题页面上第一个循环的例子一致
+        let expected = [
+            "....#.....",
+            "....+---+#",
+            "....|...|.",
+            "..#.|...|.",
+            "....|..#|.",
+            "....|...|.",
+            ".#.O^---+.",
+            "........#.",
+            "#.........",
+            "......#...",
+        ]
+        .map(|line| format!("{}\n", line))
+        .concat();
+        assert_eq!(ascii(&lab, obstruction, &traces, false), expected);
+
+        assert_eq!(traces[0].cycle().len(), traces[0].steps.len());
+        let colored = ascii(&lab, obstruction, &traces, true);
+        assert_eq!(colored.matches("\x1b[1;31m").count(), 18);
+    }
+
+    #[test]
+    fn test_svg() {
+        let lab = example();
+        let exited = svg(&lab, None, &record(&lab, None));
+        assert!(exited.starts_with("<svg"));
+        assert_eq!(exited.matches("<polyline").count(), 1);
+
+        let obstruction = Some(Point::new(3, 6));
+        let looped = svg(&lab, obstruction, &record(&lab, obstruction));
+        assert!(looped.contains("crimson"));
+        assert!(looped.contains("fill=\"orange\""));
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::{Direction, Grid, Point};
+
+/// 守卫走的一步：到达的位置、当前朝向，以及这一步是不是原地右转
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub position: Point,
+    pub direction: Direction,
+    pub turned: bool,
+}
+
+/// 一次行走的结局
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// 走出了地图
+    Exited,
+    /// 回到了走过的 (位置, 方向)，循环从第 `cycle_start` 步开始，每圈 `cycle_len` 步
+    Looped {
+        cycle_start: usize,
+        cycle_len: usize,
+    },
+}
+
+/// 逐步模拟守卫行走的迭代器
+///
+/// 第一项是起点，之后每次前进或右转各产生一项。迭代结束后可以用 `outcome` 取得结局。
+pub struct GuardWalk<'a> {
+    map: &'a Grid<char>,
+    obstruction: Option<Point>,
+    position: Point,
+    direction: Direction,
+    turned: bool,
+    // 每个格子按方向记录第几步经过（从 1 开始，0 表示没经过）
+    seen: Grid<[usize; 4]>,
+    steps: usize,
+    outcome: Option<Outcome>,
+}
+
+impl<'a> GuardWalk<'a> {
+    pub fn new(map: &'a Grid<char>, start: Point, direction: Direction) -> Self {
+        GuardWalk {
+            map,
+            obstruction: None,
+            position: start,
+            direction,
+            turned: false,
+            seen: map.filled_like([0; 4]),
+            steps: 0,
+            outcome: None,
+        }
+    }
+
+    /// 在 `point` 处额外放一个障碍
+    pub fn with_obstruction(mut self, point: Point) -> Self {
+        self.obstruction = Some(point);
+        self
+    }
+
+    pub fn is_blocked(&self, point: Point) -> bool {
+        self.obstruction == Some(point) || self.map[point] == '#'
+    }
+
+    /// 迭代结束后的结局，还没走完时为 `None`
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.outcome
+    }
+
+    /// 走完剩下的路并返回结局
+    pub fn finish(&mut self) -> Outcome {
+        self.by_ref().for_each(drop);
+        self.outcome.expect("迭代结束后一定有结局")
+    }
+}
+
+impl Iterator for GuardWalk<'_> {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        if self.outcome.is_some() {
+            return None;
+        }
+
+        let slot = &mut self.seen[self.position][self.direction as usize];
+        if *slot != 0 {
+            self.outcome = Some(Outcome::Looped {
+                cycle_start: *slot - 1,
+                cycle_len: self.steps + 1 - *slot,
+            });
+            return None;
+        }
+        self.steps += 1;
+        *slot = self.steps;
+
+        let step = Step {
+            position: self.position,
+            direction: self.direction,
+            turned: self.turned,
+        };
+
+        // 先算好下一个状态，走出地图时记下结局，下一次调用返回 None
+        match self.map.step(self.position, self.direction) {
+            None => self.outcome = Some(Outcome::Exited),
+            Some(next) if self.is_blocked(next) => {
+                self.direction = self.direction.turn_right();
+                self.turned = true;
+            }
+            Some(next) => {
+                self.position = next;
+                self.turned = false;
+            }
+        }
+
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit() {
+        let map: Grid<char> = [".#.", "...", "..."].join("\n").parse().unwrap();
+        let mut walk = GuardWalk::new(&map, Point::new(1, 2), Direction::Up);
+        let steps: Vec<_> = walk
+            .by_ref()
+            .map(|step| (step.position, step.direction, step.turned))
+            .collect();
+
+        assert_eq!(
+            steps,
+            vec![
+                (Point::new(1, 2), Direction::Up, false),
+                (Point::new(1, 1), Direction::Up, false),
+                (Point::new(1, 1), Direction::Right, true),
+                (Point::new(2, 1), Direction::Right, false),
+            ]
+        );
+        assert_eq!(walk.outcome(), Some(Outcome::Exited));
+    }
+
+    #[test]
+    fn test_loop() {
+        let map: Grid<char> = [".#..", "...#", "#...", "..#."].join("\n").parse().unwrap();
+        let mut walk = GuardWalk::new(&map, Point::new(1, 3), Direction::Up);
+
+        // 第 0 步是起点，之后在 (1,2) (1,1) (2,1) (2,2) 四个格子里各走两步，绕成一圈
+        assert_eq!(
+            walk.finish(),
+            Outcome::Looped {
+                cycle_start: 1,
+                cycle_len: 8
+            }
+        );
+    }
+
+    #[test]
+    fn test_obstruction() {
+        let map: Grid<char> = ["...", "...", "..."].join("\n").parse().unwrap();
+        let mut walk = GuardWalk::new(&map, Point::new(1, 2), Direction::Up)
+            .with_obstruction(Point::new(1, 0));
+
+        assert!(walk.any(|step| step.turned && step.position == Point::new(1, 1)));
+        assert_eq!(walk.finish(), Outcome::Exited);
+    }
+}
@@ -0,0 +1,75 @@
+//! 比较串行和并行求解的耗时，并确认两者的总和一致
+//!
+//! ```bash
+//! cargo run --release -p day7 --features parallel --example parallel -- --iterations 20
+//! ```
+
+use std::time::{Duration, Instant};
+
+use anyhow::{ensure, Result};
+use aoc_common::{InputSource, Solution};
+use clap::Parser;
+use day7::{
+    operator::Operator, solve_parallel, solve_serial, Day7, Equation, Number, PART1_OPERATORS,
+    PART2_OPERATORS,
+};
+
+#[derive(Parser)]
+struct Args {
+    /// 输入文件路径，`-` 表示从标准输入读取；默认是 day7/data.txt
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// 每种方式运行多少次，取中位数
+    #[arg(short = 'n', long, default_value_t = 20)]
+    iterations: usize,
+}
+
+type Solver = fn(&[Equation], &[&dyn Operator]) -> Number;
+
+// 运行多次，返回结果和耗时的中位数
+fn measure(
+    solver: Solver,
+    equations: &[Equation],
+    operators: &[&dyn Operator],
+    iterations: usize,
+) -> (Number, Duration) {
+    let mut samples = vec![];
+    let mut total = solver(equations, operators);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        total = solver(equations, operators);
+        samples.push(start.elapsed());
+    }
+    samples.sort_unstable();
+    (total, samples[samples.len() / 2])
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    ensure!(args.iterations > 0, "iterations must be positive");
+    let source = args.input.unwrap_or_else(Day7::data_file);
+    let equations = Day7::parse(&source.read()?)?;
+
+    for (part, operators) in [(1, &PART1_OPERATORS[..]), (2, &PART2_OPERATORS[..])] {
+        let (serial, serial_time) = measure(solve_serial, &equations, operators, args.iterations);
+        let (parallel, parallel_time) =
+            measure(solve_parallel, &equations, operators, args.iterations);
+        ensure!(
+            serial == parallel,
+            "part {}: serial total {} differs from parallel total {}",
+            part,
+            serial,
+            parallel
+        );
+        println!(
+            "part {}: serial {:>12?}  parallel {:>12?}  speedup {:.2}x  (total {})",
+            part,
+            serial_time,
+            parallel_time,
+            serial_time.as_secs_f64() / parallel_time.as_secs_f64(),
+            serial
+        );
+    }
+    Ok(())
+}
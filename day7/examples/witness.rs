@@ -0,0 +1,64 @@
+//! 列出每个能成立的方程用了哪些运算符，排查答案不对的时候用
+//!
+//! ```bash
+//! cargo run -p day7 --example witness -- --part 2 --all
+//! ```
+
+use anyhow::Result;
+use aoc_common::{InputSource, Solution};
+use clap::Parser;
+use day7::{operator::Operator, Day7, PART1_OPERATORS, PART2_OPERATORS};
+
+#[derive(Parser)]
+struct Args {
+    /// 输入文件路径，`-` 表示从标准输入读取；默认是 day7/data.txt
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// 用第几部分的运算符
+    #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// 列出所有组合，而不只是第一个
+    #[arg(long)]
+    all: bool,
+
+    /// 只输出每个方程有多少种组合
+    #[arg(long, conflicts_with = "all")]
+    count: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let source = args.input.unwrap_or_else(Day7::data_file);
+    let equations = Day7::parse(&source.read()?)?;
+    let operators: &[&dyn Operator] = match args.part {
+        1 => &PART1_OPERATORS,
+        _ => &PART2_OPERATORS,
+    };
+
+    let mut solvable = 0;
+    for equation in &equations {
+        if args.count {
+            let count = equation.count_assignments(operators);
+            if count > 0 {
+                println!("{} -> {} assignments", equation, count);
+            }
+            solvable += usize::from(count > 0);
+        } else if args.all {
+            let witnesses = equation.witnesses(operators);
+            witnesses.iter().for_each(|witness| println!("{}", witness));
+            solvable += usize::from(!witnesses.is_empty());
+        } else if let Some(witness) = equation.witness(operators) {
+            println!("{}", witness);
+            solvable += 1;
+        }
+    }
+    println!(
+        "{} of {} equations solvable, total {}",
+        solvable,
+        equations.len(),
+        day7::solve(&equations, operators)
+    );
+    Ok(())
+}
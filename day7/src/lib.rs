@@ -0,0 +1,332 @@
+pub mod operator;
+pub mod parse;
+pub mod witness;
+
+use aoc_common::{data_file, Answer, InputSource, Solution};
+use num_traits::Zero;
+use operator::{Add, Concat, Multiply, Operator};
+use parse::{parse_equations, ParseMode};
+use std::{fmt, ops::ControlFlow};
+
+pub struct Day7;
+
+/// 方程里的数；打开 `bigint` feature 后换成任意精度整数，用于超出 i64 的输入
+#[cfg(not(feature = "bigint"))]
+pub type Number = i64;
+#[cfg(feature = "bigint")]
+pub type Number = num_bigint::BigInt;
+
+/// 第一部分能用的运算符
+pub const PART1_OPERATORS: [&dyn Operator; 2] = [&Add, &Multiply];
+/// 第二部分多了拼接
+pub const PART2_OPERATORS: [&dyn Operator; 3] = [&Add, &Multiply, &Concat];
+
+// 解析输入行的结构体
+#[derive(Debug)]
+pub struct Equation {
+    test_value: Number,
+    numbers: Vec<Number>,
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.test_value)?;
+        for number in &self.numbers {
+            write!(f, " {}", number)?;
+        }
+        Ok(())
+    }
+}
+
+// 从第一个数开始正着算，每算完一组运算符就把结果和用到的运算符（从前往后排列）交给 visit
+// 溢出的分支直接丢掉
+fn forward<'o, F>(
+    value: &Number,
+    numbers: &[Number],
+    operators: &[&'o dyn Operator],
+    chosen: &mut Vec<&'o dyn Operator>,
+    visit: &mut F,
+) -> ControlFlow<()>
+where
+    F: FnMut(&Number, &[&'o dyn Operator]) -> ControlFlow<()>,
+{
+    let [next, rest @ ..] = numbers else {
+        return visit(value, chosen);
+    };
+    for op in operators {
+        let Some(value) = op.apply(value, next) else {
+            continue;
+        };
+        chosen.push(*op);
+        let flow = forward(&value, rest, operators, chosen, visit);
+        chosen.pop();
+        flow?;
+    }
+    ControlFlow::Continue(())
+}
+
+// 从最后一个数往前倒推，只沿着能整除、后缀匹配、不变成负数的分支继续
+// 每找到一组运算符（从后往前排列）就交给 visit，visit 返回 Break 时整个搜索停下
+fn search<'o>(
+    target: &Number,
+    numbers: &[Number],
+    operators: &[&'o dyn Operator],
+    non_negative: bool,
+    chosen: &mut Vec<&'o dyn Operator>,
+    visit: &mut dyn FnMut(&[&'o dyn Operator]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    match numbers {
+        [] => ControlFlow::Continue(()),
+        [first] if first == target => visit(chosen),
+        [_] => ControlFlow::Continue(()),
+        [rest @ .., last] => {
+            for op in operators {
+                chosen.push(*op);
+                let flow = if op.inverse_is_unique(last) {
+                    match op.try_inverse(target, last) {
+                        Some(prev) if !(non_negative && prev < Number::zero()) => {
+                            search(&prev, rest, operators, non_negative, chosen, visit)
+                        }
+                        _ => ControlFlow::Continue(()),
+                    }
+                } else {
+                    // 例如乘 0：前面算出什么都可能成立，只能把前面的组合正着算一遍
+                    forward(
+                        &rest[0],
+                        &rest[1..],
+                        operators,
+                        &mut Vec::new(),
+                        &mut |value, path| {
+                            if op.apply(value, last).as_ref() != Some(target) {
+                                return ControlFlow::Continue(());
+                            }
+                            let len = chosen.len();
+                            chosen.extend(path.iter().rev());
+                            let flow = visit(chosen);
+                            chosen.truncate(len);
+                            flow
+                        },
+                    )
+                };
+                chosen.pop();
+                flow?;
+            }
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl Equation {
+    // 把每组能让方程成立的运算符（从后往前排列）交给 visit，返回 Break 时提前结束
+    fn search<'o>(
+        &self,
+        operators: &[&'o dyn Operator],
+        visit: &mut dyn FnMut(&[&'o dyn Operator]) -> ControlFlow<()>,
+    ) {
+        // 运算符和每个数都不会产生负数时，中间结果才一定是非负的
+        let non_negative = operators.iter().all(|op| op.keeps_non_negative())
+            && self.numbers.iter().all(|number| *number >= Number::zero());
+        let _ = search(
+            &self.test_value,
+            &self.numbers,
+            operators,
+            non_negative,
+            &mut Vec::new(),
+            visit,
+        );
+    }
+}
+
+// 检查是否有任何组合能得到测试值，找到一个就返回
+fn is_solvable(equation: &Equation, operators: &[&dyn Operator]) -> bool {
+    let mut found = false;
+    equation.search(operators, &mut |_| {
+        found = true;
+        ControlFlow::Break(())
+    });
+    found
+}
+
+/// 只用 `operators` 里的运算符能成立的方程，把它们的测试值加起来，一个一个地算
+pub fn solve_serial(equations: &[Equation], operators: &[&dyn Operator]) -> Number {
+    equations
+        .iter()
+        .filter(|equation| is_solvable(equation, operators))
+        .map(|equation| &equation.test_value)
+        .sum()
+}
+
+/// 和 `solve_serial` 一样，但用 rayon 并行检查各个方程
+///
+/// 方程之间互不影响，整数加法和顺序无关，所以总和和串行完全一致。
+#[cfg(feature = "parallel")]
+pub fn solve_parallel(equations: &[Equation], operators: &[&dyn Operator]) -> Number {
+    use rayon::prelude::*;
+
+    equations
+        .par_iter()
+        .filter(|equation| is_solvable(equation, operators))
+        .map(|equation| &equation.test_value)
+        .sum()
+}
+
+/// 打开 `parallel` feature 时并行求解，否则串行
+pub fn solve(equations: &[Equation], operators: &[&dyn Operator]) -> Number {
+    #[cfg(feature = "parallel")]
+    return solve_parallel(equations, operators);
+
+    #[cfg(not(feature = "parallel"))]
+    solve_serial(equations, operators)
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<Equation>;
+
+    fn data_file() -> InputSource {
+        data_file!()
+    }
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        // 把所有出错的行一起报出来，而不是悄悄跳过
+        Ok(parse_equations(input, ParseMode::CollectAll)?)
+    }
+
+    fn part1(equations: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(solve(equations, &PART1_OPERATORS).to_string().into())
+    }
+
+    fn part2(equations: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(solve(equations, &PART2_OPERATORS).to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use operator::{Subtract, Xor};
+
+    aoc_common::example_tests! {
+        Day7;
+        test_example_part1: part1("examples/part1.txt") => 3749,
+        test_example_part2: part2("examples/part1.txt") => 11387,
+    }
+
+    #[test]
+    fn test_example() {
+        let input = vec![
+            "190: 10 19",
+            "3267: 81 40 27",
+            "83: 17 5",
+            "156: 15 6",
+            "7290: 6 8 6 15",
+            "161011: 16 10 13",
+            "192: 17 8 14",
+            "21037: 9 7 18 13",
+            "292: 11 6 16 20",
+        ];
+
+        let equations = parse_equations(&input.join("\n"), ParseMode::FailFast).unwrap();
+        assert_eq!(equations.len(), input.len());
+
+        assert_eq!(solve(&equations, &PART1_OPERATORS), Number::from(3749));
+        assert_eq!(solve(&equations, &PART2_OPERATORS), Number::from(11387));
+    }
+
+    #[test]
+    fn test_concat() {
+        // 156 = 15 || 6
+        let equation: Equation = "156: 15 6".parse().unwrap();
+        assert!(!is_solvable(&equation, &PART1_OPERATORS));
+        assert!(is_solvable(&equation, &PART2_OPERATORS));
+
+        // 7290 = 6 * 8 || 6 * 15
+        let equation: Equation = "7290: 6 8 6 15".parse().unwrap();
+        assert!(!is_solvable(&equation, &PART1_OPERATORS));
+        assert!(is_solvable(&equation, &PART2_OPERATORS));
+    }
+
+    #[test]
+    fn test_other_operators() {
+        // 10 - 3 - 2 = 5
+        let equation: Equation = "5: 10 3 2".parse().unwrap();
+        assert!(!is_solvable(&equation, &PART1_OPERATORS));
+        assert!(is_solvable(&equation, &[&Add, &Subtract]));
+
+        // 有减法时中间结果可以是负数：(1 - 3) * 2 + 10 = 6
+        let equation: Equation = "6: 1 3 2 10".parse().unwrap();
+        assert!(is_solvable(&equation, &[&Add, &Multiply, &Subtract]));
+
+        // 10 ^ 3 = 9
+        let equation: Equation = "9: 10 3".parse().unwrap();
+        assert!(!is_solvable(&equation, &PART2_OPERATORS));
+        assert!(is_solvable(&equation, &[&Add, &Xor]));
+    }
+
+    #[test]
+    fn test_zero_and_negative() {
+        // 5 * 0 + 10 = 10，乘 0 没法倒推
+        let equation: Equation = "10: 5 0 10".parse().unwrap();
+        assert!(is_solvable(&equation, &PART1_OPERATORS));
+
+        let equation: Equation = "0: 7 0".parse().unwrap();
+        assert!(is_solvable(&equation, &PART1_OPERATORS));
+
+        // 输入里有负数时不能剪掉负数分支
+        let equation: Equation = "0: -5 5".parse().unwrap();
+        assert!(is_solvable(&equation, &PART1_OPERATORS));
+
+        let equation: Equation = "-6: -3 2".parse().unwrap();
+        assert!(is_solvable(&equation, &PART1_OPERATORS));
+
+        let equation: Equation = "1: 7 0".parse().unwrap();
+        assert!(!is_solvable(&equation, &PART2_OPERATORS));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 倒推 9223372036854775807 + 9223372036854775807 会溢出，当作算不出来
+        let equation: Equation = "9223372036854775807: 1 9223372036854775807"
+            .parse()
+            .unwrap();
+        assert!(!is_solvable(&equation, &[&Add, &Subtract]));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint() {
+        // 10^20 超出了 i64
+        let equation: Equation = "100000000000000000000: 10000000000 10000000000"
+            .parse()
+            .unwrap();
+        assert!(is_solvable(&equation, &PART1_OPERATORS));
+        assert_eq!(
+            solve(&[equation], &PART1_OPERATORS).to_string(),
+            "100000000000000000000"
+        );
+
+        // 拼接出 20 位数
+        let equation: Equation = "12345678901234567890: 1234567890 1234567890"
+            .parse()
+            .unwrap();
+        assert!(is_solvable(&equation, &PART2_OPERATORS));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel() {
+        // 多复制几份例子，让 rayon 真的分到多个线程上
+        let example = aoc_common::embedded_input!("examples/part1.txt")
+            .read()
+            .unwrap();
+        let equations = Day7::parse(&example.repeat(100)).unwrap();
+        for operators in [&PART1_OPERATORS[..], &PART2_OPERATORS[..]] {
+            assert_eq!(
+                solve_parallel(&equations, operators),
+                solve_serial(&equations, operators)
+            );
+        }
+    }
+}
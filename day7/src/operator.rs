@@ -0,0 +1,215 @@
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+
+use crate::Number;
+
+/// 方程里两个数之间可以填的运算符，从左到右计算，没有优先级
+///
+/// 计算都是检查过溢出的：溢出就当作这条路算不出目标值，返回 `None`。
+/// `Number` 可能是 i64 也可能是 BigInt，两者的同名方法参数不同，所以统一写成 `Trait::method(a, b)`。
+/// 要求 `Sync` 是为了并行求解时能在线程间共享同一组运算符。
+pub trait Operator: Sync {
+    /// 显示用的符号，例如 `+`
+    fn symbol(&self) -> &'static str;
+
+    /// 计算 `a op b`，溢出时返回 `None`
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number>;
+
+    /// 已知 `a op b = target` 和 `b`，反推出 `a`；不可能得到 `target` 时返回 `None`
+    ///
+    /// `inverse_is_unique` 为 false 时不会被调用。
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number>;
+
+    /// 给定 `b` 时 `a` 是否能由结果唯一确定，不能时倒推改为正着把前面的组合都算一遍
+    fn inverse_is_unique(&self, _b: &Number) -> bool {
+        true
+    }
+
+    /// 两个非负数算完是否一定还是非负数，运算符都满足时倒推可以剪掉负数分支
+    fn keeps_non_negative(&self) -> bool {
+        true
+    }
+}
+
+pub struct Add;
+pub struct Multiply;
+/// 把右边的数字直接接在左边后面，例如 12 || 345 = 12345
+pub struct Concat;
+pub struct Subtract;
+pub struct Xor;
+
+impl Operator for Add {
+    fn symbol(&self) -> &'static str {
+        "+"
+    }
+
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number> {
+        CheckedAdd::checked_add(a, b)
+    }
+
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number> {
+        CheckedSub::checked_sub(target, b)
+    }
+}
+
+impl Operator for Multiply {
+    fn symbol(&self) -> &'static str {
+        "*"
+    }
+
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number> {
+        CheckedMul::checked_mul(a, b)
+    }
+
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number> {
+        let quotient = CheckedDiv::checked_div(target, b)?;
+        (CheckedMul::checked_mul(&quotient, b)? == *target).then_some(quotient)
+    }
+
+    // 乘 0 时 a 可以是任意值
+    fn inverse_is_unique(&self, b: &Number) -> bool {
+        !b.is_zero()
+    }
+}
+
+// 比 b 多一位的 10 的幂，把 b 接在后面时左边要乘上它；溢出时为 None
+fn concat_shift(b: &Number) -> Option<Number> {
+    let ten = Number::from(10);
+    let mut shift = Number::from(10);
+    while shift <= *b {
+        shift = CheckedMul::checked_mul(&shift, &ten)?;
+    }
+    Some(shift)
+}
+
+impl Operator for Concat {
+    fn symbol(&self) -> &'static str {
+        "||"
+    }
+
+    // 和 try_inverse 一样只接受非负数，两个方向才一致
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number> {
+        if *a < Number::zero() || *b < Number::zero() {
+            return None;
+        }
+        let shifted = CheckedMul::checked_mul(a, &concat_shift(b)?)?;
+        CheckedAdd::checked_add(&shifted, b)
+    }
+
+    // 只对非负数有意义
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number> {
+        if *target < Number::zero() || *b < Number::zero() {
+            return None;
+        }
+        let shift = concat_shift(b)?;
+        let prefix = CheckedDiv::checked_div(target, &shift)?;
+        let suffix = CheckedSub::checked_sub(target, &CheckedMul::checked_mul(&prefix, &shift)?)?;
+        (suffix == *b).then_some(prefix)
+    }
+}
+
+impl Operator for Subtract {
+    fn symbol(&self) -> &'static str {
+        "-"
+    }
+
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number> {
+        CheckedSub::checked_sub(a, b)
+    }
+
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number> {
+        CheckedAdd::checked_add(target, b)
+    }
+
+    fn keeps_non_negative(&self) -> bool {
+        false
+    }
+}
+
+impl Operator for Xor {
+    fn symbol(&self) -> &'static str {
+        "^"
+    }
+
+    fn apply(&self, a: &Number, b: &Number) -> Option<Number> {
+        Some(a ^ b)
+    }
+
+    fn try_inverse(&self, target: &Number, b: &Number) -> Option<Number> {
+        Some(target ^ b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: i64) -> Number {
+        Number::from(value)
+    }
+
+    #[test]
+    fn test_try_inverse() {
+        assert_eq!(Add.try_inverse(&n(3267), &n(27)), Some(n(3240)));
+        assert_eq!(Multiply.try_inverse(&n(3267), &n(27)), Some(n(121)));
+        assert_eq!(Multiply.try_inverse(&n(3267), &n(28)), None);
+        assert_eq!(Multiply.try_inverse(&n(3267), &n(0)), None);
+        assert!(!Multiply.inverse_is_unique(&n(0)));
+        assert!(Multiply.inverse_is_unique(&n(27)));
+        assert!(Add.inverse_is_unique(&n(0)));
+        assert_eq!(Concat.try_inverse(&n(156), &n(6)), Some(n(15)));
+        assert_eq!(Concat.try_inverse(&n(12345), &n(345)), Some(n(12)));
+        assert_eq!(Concat.try_inverse(&n(156), &n(5)), None);
+        assert_eq!(Subtract.try_inverse(&n(5), &n(3)), Some(n(8)));
+        assert_eq!(Xor.try_inverse(&n(9), &n(3)), Some(n(10)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let operators: [&dyn Operator; 5] = [&Add, &Multiply, &Concat, &Subtract, &Xor];
+        for op in operators {
+            for (a, b) in [(15, 6), (81, 40), (7, 0), (1, 10), (-3, 4), (1, -5)] {
+                // 拼接不接受负数
+                let Some(target) = op.apply(&n(a), &n(b)) else {
+                    assert_eq!(op.symbol(), "||");
+                    assert!(a < 0 || b < 0);
+                    continue;
+                };
+                if b != 0 || op.symbol() != "*" {
+                    assert_eq!(
+                        op.try_inverse(&target, &n(b)),
+                        Some(n(a)),
+                        "{} {} {}",
+                        a,
+                        op.symbol(),
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_negative() {
+        assert_eq!(Concat.apply(&n(1), &n(-5)), None);
+        assert_eq!(Concat.apply(&n(-1), &n(5)), None);
+        assert_eq!(Concat.try_inverse(&n(5), &n(-5)), None);
+    }
+
+    #[test]
+    fn test_concat_shift() {
+        assert_eq!(concat_shift(&n(345)), Some(n(1000)));
+        assert_eq!(concat_shift(&n(6)), Some(n(10)));
+        assert_eq!(concat_shift(&n(0)), Some(n(10)));
+        assert_eq!(concat_shift(&n(10)), Some(n(100)));
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    #[test]
+    fn test_overflow() {
+        assert_eq!(Multiply.apply(&i64::MAX, &2), None);
+        assert_eq!(Concat.apply(&i64::MAX, &1), None);
+        assert_eq!(concat_shift(&1_000_000_000_000_000_000), None);
+        assert_eq!(Subtract.try_inverse(&i64::MAX, &1), None);
+        assert_eq!(Multiply.try_inverse(&i64::MIN, &-1), None);
+    }
+}
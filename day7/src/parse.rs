@@ -0,0 +1,207 @@
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{Equation, Number};
+
+/// 一行方程哪里写错了
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    #[error("expected ':' after test value")]
+    MissingColon,
+
+    #[error("invalid test value {0:?}")]
+    InvalidTestValue(String),
+
+    #[error("invalid number {0:?}")]
+    InvalidNumber(String),
+
+    #[error("expected at least one number after ':'")]
+    NoNumbers,
+}
+
+/// 解析方程出错的位置，行号和列号都从 1 开始，列号按字节计算
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}, column {column}: {kind}")]
+pub struct EquationParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+/// 遇到错误时是立刻返回，还是把所有出错的行都找出来
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    #[default]
+    FailFast,
+    CollectAll,
+}
+
+/// 所有出错的行，`FailFast` 时只有一个
+#[derive(Error, Debug, PartialEq, Eq)]
+pub struct EquationParseErrors(pub Vec<EquationParseError>);
+
+impl fmt::Display for EquationParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} malformed equation(s)", self.0.len())?;
+        for err in &self.0 {
+            write!(f, "\n  {}", err)?;
+        }
+        Ok(())
+    }
+}
+
+// 按空白切分，同时给出每一段在 s 里的字节偏移
+fn tokens(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - s.as_ptr() as usize, token))
+}
+
+// 解析一行，出错时返回错误类型和列号
+fn parse_line(line: &str) -> Result<Equation, (usize, ErrorKind)> {
+    let Some((head, tail)) = line.split_once(':') else {
+        // 冒号应该紧跟在第一段后面
+        let column = tokens(line)
+            .next()
+            .map_or(0, |(at, token)| at + token.len());
+        return Err((column + 1, ErrorKind::MissingColon));
+    };
+
+    let (at, token) = tokens(head).next().unwrap_or((0, ""));
+    let test_value = token
+        .parse::<Number>()
+        .ok()
+        .filter(|_| tokens(head).count() == 1)
+        .ok_or_else(|| (at + 1, ErrorKind::InvalidTestValue(head.trim().to_string())))?;
+
+    let offset = head.len() + 1;
+    let numbers = tokens(tail)
+        .map(|(at, token)| {
+            token
+                .parse::<Number>()
+                .map_err(|_| (offset + at + 1, ErrorKind::InvalidNumber(token.to_string())))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.is_empty() {
+        return Err((offset + 1, ErrorKind::NoNumbers));
+    }
+
+    Ok(Equation {
+        test_value,
+        numbers,
+    })
+}
+
+impl FromStr for Equation {
+    type Err = EquationParseError;
+
+    /// 单独解析一行，行号固定为 1
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_line(s).map_err(|(column, kind)| EquationParseError {
+            line: 1,
+            column,
+            kind,
+        })
+    }
+}
+
+/// 逐行解析方程，跳过空行
+pub fn parse_equations(input: &str, mode: ParseMode) -> Result<Vec<Equation>, EquationParseErrors> {
+    let mut equations = Vec::new();
+    let mut errors = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_line(line) {
+            Ok(equation) => equations.push(equation),
+            Err((column, kind)) => {
+                errors.push(EquationParseError {
+                    line: i + 1,
+                    column,
+                    kind,
+                });
+                if mode == ParseMode::FailFast {
+                    break;
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(equations)
+    } else {
+        Err(EquationParseErrors(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: &str) -> (usize, ErrorKind) {
+        let err = line.parse::<Equation>().err().unwrap();
+        (err.column, err.kind)
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let equation: Equation = "3267: 81 40 27".parse().unwrap();
+        assert_eq!(equation.to_string(), "3267: 81 40 27");
+
+        assert_eq!(error("3267 81 40 27"), (5, ErrorKind::MissingColon));
+        assert_eq!(
+            error("32a7: 81 40"),
+            (1, ErrorKind::InvalidTestValue("32a7".to_string()))
+        );
+        assert_eq!(
+            error("3267: 81 4x0 27"),
+            (10, ErrorKind::InvalidNumber("4x0".to_string()))
+        );
+        assert_eq!(error("3267:"), (6, ErrorKind::NoNumbers));
+        assert_eq!(error("3267:   "), (6, ErrorKind::NoNumbers));
+    }
+
+    #[test]
+    fn test_parse_equations() {
+        let input = "190: 10 19\n\n3267 81\n83: 17 5\n156: 1x 6\n";
+
+        let errors = parse_equations(input, ParseMode::FailFast).unwrap_err();
+        assert_eq!(
+            errors.0,
+            vec![EquationParseError {
+                line: 3,
+                column: 5,
+                kind: ErrorKind::MissingColon
+            }]
+        );
+
+        let errors = parse_equations(input, ParseMode::CollectAll).unwrap_err();
+        assert_eq!(
+            errors.0.iter().map(|err| err.line).collect::<Vec<_>>(),
+            vec![3, 5]
+        );
+        assert_eq!(
+            errors.to_string(),
+            [
+                "2 malformed equation(s)",
+                "  line 3, column 5: expected ':' after test value",
+                "  line 5, column 6: invalid number \"1x\"",
+            ]
+            .join("\n")
+        );
+
+        let equations = parse_equations("190: 10 19\n\n83: 17 5\n", ParseMode::FailFast).unwrap();
+        assert_eq!(equations.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_zero_and_negative() {
+        // 0 和负数都是合法输入
+        for line in ["0: 7 0", "-6: -3 2"] {
+            let equation: Equation = line.parse().unwrap();
+            assert_eq!(equation.to_string(), line);
+        }
+    }
+}
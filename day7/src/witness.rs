@@ -0,0 +1,131 @@
+use std::{fmt, ops::ControlFlow};
+
+use crate::{operator::Operator, Equation};
+
+/// 一组能让方程成立的运算符，显示为 `81 + 40 * 27 = 3267`
+pub struct Witness<'a> {
+    equation: &'a Equation,
+    operators: Vec<&'a dyn Operator>,
+}
+
+impl<'a> Witness<'a> {
+    // 倒推得到的运算符是从后往前排的
+    fn from_reversed(equation: &'a Equation, reversed: &[&'a dyn Operator]) -> Self {
+        Witness {
+            equation,
+            operators: reversed.iter().rev().copied().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Witness<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = &self.equation.numbers;
+        write!(f, "{}", numbers[0])?;
+        for (op, number) in self.operators.iter().zip(&numbers[1..]) {
+            write!(f, " {} {}", op.symbol(), number)?;
+        }
+        write!(f, " = {}", self.equation.test_value)
+    }
+}
+
+impl Equation {
+    /// 找到的第一组能让方程成立的运算符
+    pub fn witness<'a>(&'a self, operators: &[&'a dyn Operator]) -> Option<Witness<'a>> {
+        let mut found = None;
+        self.search(operators, &mut |reversed| {
+            found = Some(Witness::from_reversed(self, reversed));
+            ControlFlow::Break(())
+        });
+        found
+    }
+
+    /// 所有能让方程成立的运算符组合
+    pub fn witnesses<'a>(&'a self, operators: &[&'a dyn Operator]) -> Vec<Witness<'a>> {
+        let mut found = Vec::new();
+        self.search(operators, &mut |reversed| {
+            found.push(Witness::from_reversed(self, reversed));
+            ControlFlow::Continue(())
+        });
+        found
+    }
+
+    /// 能让方程成立的运算符组合有多少种，不保存每一组
+    pub fn count_assignments(&self, operators: &[&dyn Operator]) -> usize {
+        let mut count = 0;
+        self.search(operators, &mut |_| {
+            count += 1;
+            ControlFlow::Continue(())
+        });
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PART1_OPERATORS, PART2_OPERATORS};
+
+    fn rendered(line: &str, operators: &[&dyn Operator]) -> Vec<String> {
+        let equation: Equation = line.parse().unwrap();
+        let mut witnesses: Vec<String> = equation
+            .witnesses(operators)
+            .iter()
+            .map(|witness| witness.to_string())
+            .collect();
+        witnesses.sort();
+        witnesses
+    }
+
+    #[test]
+    fn test_witnesses() {
+        assert_eq!(
+            rendered("3267: 81 40 27", &PART1_OPERATORS),
+            vec!["81 * 40 + 27 = 3267", "81 + 40 * 27 = 3267"]
+        );
+        assert_eq!(
+            rendered("7290: 6 8 6 15", &PART2_OPERATORS),
+            vec!["6 * 8 || 6 * 15 = 7290"]
+        );
+        assert!(rendered("83: 17 5", &PART2_OPERATORS).is_empty());
+    }
+
+    #[test]
+    fn test_witness() {
+        let equation: Equation = "292: 11 6 16 20".parse().unwrap();
+        assert_eq!(
+            equation.witness(&PART1_OPERATORS).unwrap().to_string(),
+            "11 + 6 * 16 + 20 = 292"
+        );
+        assert_eq!(equation.count_assignments(&PART1_OPERATORS), 1);
+
+        let equation: Equation = "3267: 81 40 27".parse().unwrap();
+        assert_eq!(equation.count_assignments(&PART1_OPERATORS), 2);
+
+        // 2 + 2 和 2 * 2 算两种
+        let equation: Equation = "4: 2 2".parse().unwrap();
+        assert_eq!(equation.count_assignments(&PART2_OPERATORS), 2);
+    }
+
+    #[test]
+    fn test_zero_operands() {
+        // 0 + 0 和 0 * 0 算两种
+        let equation: Equation = "0: 0 0".parse().unwrap();
+        assert_eq!(equation.count_assignments(&PART1_OPERATORS), 2);
+
+        let equation: Equation = "0: 7 0".parse().unwrap();
+        assert_eq!(equation.count_assignments(&PART1_OPERATORS), 1);
+
+        // 乘 0 时正着算前面的组合，拼接也不能用在负数上
+        assert_eq!(
+            rendered("0: 1 -5 0", &PART2_OPERATORS),
+            vec!["1 * -5 * 0 = 0", "1 + -5 * 0 = 0"]
+        );
+
+        // 乘 0 前面的组合都要列出来
+        assert_eq!(
+            rendered("0: 2 3 0", &PART1_OPERATORS),
+            vec!["2 * 3 * 0 = 0", "2 + 3 * 0 = 0"]
+        );
+    }
+}